@@ -1,7 +1,36 @@
+mod action_code;
+mod config;
+mod guard;
+mod message;
+mod multi_factor;
+mod phone;
+mod provider;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+mod token;
 mod user;
 
 use crate::FirebaseError;
-use std::{error::Error, fmt};
+pub use action_code::*;
+pub use config::*;
+use futures::{Future, Stream, StreamExt};
+pub use guard::*;
+pub use message::*;
+pub use multi_factor::*;
+pub use phone::*;
+pub use provider::*;
+#[cfg(feature = "snapshot")]
+pub use snapshot::*;
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    error::Error,
+    fmt,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll, Waker},
+};
+pub use token::*;
 pub use user::*;
 use wasm_bindgen::{prelude::*, JsCast};
 
@@ -26,76 +55,439 @@ impl Error for AuthError {
 
 impl From<FirebaseError> for AuthError {
     fn from(err: FirebaseError) -> Self {
-        Self {
-            kind: err.code().parse().unwrap(),
-            source: err,
+        let kind = err.code().parse().unwrap();
+
+        Self { kind, source: err }
+    }
+}
+
+impl AuthError {
+    /// The email address associated with the error, if any, such as for
+    /// [`AuthErrorKind::AccountExistsWithDifferentCredential`].
+    pub fn email(&self) -> Option<String> {
+        let custom_data = js_sys::Reflect::get(&self.source, &"customData".into()).ok()?;
+
+        if custom_data.is_undefined() || custom_data.is_null() {
+            return None;
         }
+
+        js_sys::Reflect::get(&custom_data, &"email".into())
+            .ok()?
+            .as_string()
+    }
+
+    /// Whether the operation failed because the user last signed in too long
+    /// ago, in which case they must be reauthenticated with
+    /// [`reauthenticate_with_credential`] before retrying.
+    pub fn requires_recent_login(&self) -> bool {
+        matches!(self.kind, AuthErrorKind::RequiresRecentLogin)
     }
 }
 
-#[derive(Clone, Debug, strum::EnumString)]
+/// The error codes emitted by the Firebase Auth SDK.
+///
+/// See [`AuthErrorCodes`](https://firebase.google.com/docs/reference/js/auth#autherrorcodes).
+#[derive(Clone, Debug, PartialEq, Eq, strum::EnumString)]
 #[non_exhaustive]
 pub enum AuthErrorKind {
+    /// An account already exists with the same email address but a different
+    /// sign-in method. See [`account_linking_info`].
+    #[strum(serialize = "auth/account-exists-with-different-credential")]
+    AccountExistsWithDifferentCredential,
+    /// The operation is restricted to administrators, such as anonymous sign-up
+    /// when it is disabled.
+    #[strum(serialize = "auth/admin-restricted-operation")]
+    AdminRestrictedOperation,
+    /// [`initialize_auth`] was called more than once for the same app.
+    #[strum(serialize = "auth/already-initialized")]
+    AlreadyInitialized,
+    /// The Firebase app was deleted.
     #[strum(serialize = "auth/app-deleted")]
     AppDeleted,
+    /// The app's domain is not authorized to use the API key.
     #[strum(serialize = "auth/app-not-authorized")]
     AppNotAuthorized,
+    /// The mobile app to open an action link in is not installed.
+    #[strum(serialize = "auth/app-not-installed")]
+    AppNotInstalled,
+    /// An invalid argument was passed to the SDK.
     #[strum(serialize = "auth/argument-error")]
     ArgumentError,
+    /// The `authDomain` option is missing from the app configuration.
+    #[strum(serialize = "auth/auth-domain-config-required")]
+    AuthDomainConfigRequired,
+    /// A popup was cancelled because another one was opened.
+    #[strum(serialize = "auth/cancelled-popup-request")]
+    CancelledPopupRequest,
+    /// The reCAPTCHA response was invalid, expired, or issued for a different
+    /// domain.
+    #[strum(serialize = "auth/captcha-check-failed")]
+    CaptchaCheckFailed,
+    /// The SMS verification code expired.
+    #[strum(serialize = "auth/code-expired")]
+    CodeExpired,
+    /// The Cordova environment is not ready.
+    #[strum(serialize = "auth/cordova-not-ready")]
+    CordovaNotReady,
+    /// The browser does not support CORS.
+    #[strum(serialize = "auth/cors-unsupported")]
+    CorsUnsupported,
+    /// The credential is already linked to a different account.
+    #[strum(serialize = "auth/credential-already-in-use")]
+    CredentialAlreadyInUse,
+    /// The custom token was issued for a different project.
+    #[strum(serialize = "auth/custom-token-mismatch")]
+    CustomTokenMismatch,
+    /// Another Firebase SDK called `getAuth` before [`initialize_auth`].
+    #[strum(serialize = "auth/dependent-sdk-initialized-before-auth")]
+    DependentSdkInitializedBeforeAuth,
+    /// Dynamic Links are not enabled for the project.
+    #[strum(serialize = "auth/dynamic-link-not-activated")]
+    DynamicLinkNotActivated,
+    /// An account already exists with the email address.
+    #[strum(serialize = "auth/email-already-in-use")]
+    EmailAlreadyInUse,
+    /// Multi-factor users must verify a new email address before it is changed.
+    #[strum(serialize = "auth/email-change-needs-verification")]
+    EmailChangeNeedsVerification,
+    /// The emulator was configured after the instance was used.
+    #[strum(serialize = "auth/emulator-config-failed")]
+    EmulatorConfigFailed,
+    /// The email action code expired.
+    #[strum(serialize = "auth/expired-action-code")]
+    ExpiredActionCode,
+    /// An unexpected error occurred within the SDK or the backend.
+    #[strum(serialize = "auth/internal-error")]
+    InternalError,
+    /// The email action code is malformed or was already used.
+    #[strum(serialize = "auth/invalid-action-code")]
+    InvalidActionCode,
+    /// The API key is invalid.
     #[strum(serialize = "auth/invalid-api-key")]
     InvalidApiKey,
-    #[strum(serialize = "auth/invalid-user-token")]
-    InvalidUserToken,
+    /// The reCAPTCHA token or app verification is invalid.
+    #[strum(serialize = "auth/invalid-app-credential")]
+    InvalidAppCredential,
+    /// The mobile app ID is not registered in the project.
+    #[strum(serialize = "auth/invalid-app-id")]
+    InvalidAppId,
+    /// The popup or redirect returned an invalid event.
+    #[strum(serialize = "auth/invalid-auth-event")]
+    InvalidAuthEvent,
+    /// The SHA-1 certificate hash is invalid.
+    #[strum(serialize = "auth/invalid-cert-hash")]
+    InvalidCertHash,
+    /// The continue URL in [`ActionCodeSettings`] is invalid.
+    #[strum(serialize = "auth/invalid-continue-uri")]
+    InvalidContinueUri,
+    /// The Cordova plugins required for OAuth sign-in are missing.
+    #[strum(serialize = "auth/invalid-cordova-configuration")]
+    InvalidCordovaConfiguration,
+    /// The credential is malformed or expired, or the email and password do not
+    /// match, when email enumeration protection is enabled.
+    #[strum(serialize = "auth/invalid-credential")]
+    InvalidCredential,
+    /// The custom token is malformed.
+    #[strum(serialize = "auth/invalid-custom-token")]
+    InvalidCustomToken,
+    /// The Dynamic Link domain is not configured for the project.
+    #[strum(serialize = "auth/invalid-dynamic-link-domain")]
+    InvalidDynamicLinkDomain,
+    /// The email address is malformed.
+    #[strum(serialize = "auth/invalid-email")]
+    InvalidEmail,
+    /// The emulator URL does not start with `http://` or `https://`.
+    #[strum(serialize = "auth/invalid-emulator-scheme")]
+    InvalidEmulatorScheme,
+    /// The Hosting link domain is not configured for the project.
+    #[strum(serialize = "auth/invalid-hosting-link-domain")]
+    InvalidHostingLinkDomain,
+    /// The email template contains invalid characters.
+    #[strum(serialize = "auth/invalid-message-payload")]
+    InvalidMessagePayload,
+    /// The multi-factor session is invalid or expired.
+    #[strum(serialize = "auth/invalid-multi-factor-session")]
+    InvalidMultiFactorSession,
+    /// The OAuth client ID is invalid.
+    #[strum(serialize = "auth/invalid-oauth-client-id")]
+    InvalidOauthClientId,
+    /// The provider does not support this OAuth operation.
+    #[strum(serialize = "auth/invalid-oauth-provider")]
+    InvalidOauthProvider,
+    /// The persistence type is invalid for the environment.
+    #[strum(serialize = "auth/invalid-persistence-type")]
+    InvalidPersistenceType,
+    /// The phone number is malformed.
+    #[strum(serialize = "auth/invalid-phone-number")]
+    InvalidPhoneNumber,
+    /// The provider ID is invalid.
+    #[strum(serialize = "auth/invalid-provider-id")]
+    InvalidProviderId,
+    /// The reCAPTCHA action is invalid.
+    #[strum(serialize = "auth/invalid-recaptcha-action")]
+    InvalidRecaptchaAction,
+    /// The reCAPTCHA token is invalid.
+    #[strum(serialize = "auth/invalid-recaptcha-token")]
+    InvalidRecaptchaToken,
+    /// The reCAPTCHA version is invalid.
+    #[strum(serialize = "auth/invalid-recaptcha-version")]
+    InvalidRecaptchaVersion,
+    /// The email could not be delivered to the recipient.
+    #[strum(serialize = "auth/invalid-recipient-email")]
+    InvalidRecipientEmail,
+    /// The reCAPTCHA request type is invalid.
+    #[strum(serialize = "auth/invalid-req-type")]
+    InvalidReqType,
+    /// The sender of the email template is invalid.
+    #[strum(serialize = "auth/invalid-sender")]
+    InvalidSender,
+    /// The tenant ID is invalid.
     #[strum(serialize = "auth/invalid-tenant-id")]
     InvalidTenantId,
+    /// The user's credential is no longer valid, and they must sign in again.
+    #[strum(serialize = "auth/invalid-user-token")]
+    InvalidUserToken,
+    /// The SMS verification code is invalid.
+    #[strum(serialize = "auth/invalid-verification-code")]
+    InvalidVerificationCode,
+    /// The phone verification ID is invalid.
+    #[strum(serialize = "auth/invalid-verification-id")]
+    InvalidVerificationId,
+    /// The user already enrolled the maximum number of second factors.
+    #[strum(serialize = "auth/maximum-second-factor-count-exceeded")]
+    MaximumSecondFactorCountExceeded,
+    /// An Android package name is required to install the Android app.
+    #[strum(serialize = "auth/missing-android-pkg-name")]
+    MissingAndroidPackageName,
+    /// The phone verification request is missing an application verifier.
+    #[strum(serialize = "auth/missing-app-credential")]
+    MissingAppCredential,
+    /// The reCAPTCHA client type is missing.
+    #[strum(serialize = "auth/missing-client-type")]
+    MissingClientType,
+    /// A continue URL is required in [`ActionCodeSettings`].
+    #[strum(serialize = "auth/missing-continue-uri")]
+    MissingContinueUri,
+    /// An iOS bundle ID is required to install the iOS app.
+    #[strum(serialize = "auth/missing-ios-bundle-id")]
+    MissingIOSBundleId,
+    /// An internal iframe error occurred.
+    #[strum(serialize = "auth/missing-iframe-start")]
+    MissingIframeStart,
+    /// No second factor identifier was provided.
+    #[strum(serialize = "auth/missing-multi-factor-info")]
+    MissingMultiFactorInfo,
+    /// The request is missing a multi-factor session.
+    #[strum(serialize = "auth/missing-multi-factor-session")]
+    MissingMultiFactorSession,
+    /// The OIDC ID token nonce is missing or invalid.
+    #[strum(serialize = "auth/missing-or-invalid-nonce")]
+    MissingOrInvalidNonce,
+    /// No password was provided.
+    #[strum(serialize = "auth/missing-password")]
+    MissingPassword,
+    /// No phone number was provided.
+    #[strum(serialize = "auth/missing-phone-number")]
+    MissingPhoneNumber,
+    /// The reCAPTCHA token is missing.
+    #[strum(serialize = "auth/missing-recaptcha-token")]
+    MissingRecaptchaToken,
+    /// The reCAPTCHA version is missing.
+    #[strum(serialize = "auth/missing-recaptcha-version")]
+    MissingRecaptchaVersion,
+    /// No SMS verification code was provided.
+    #[strum(serialize = "auth/missing-verification-code")]
+    MissingVerificationCode,
+    /// No phone verification ID was provided.
+    #[strum(serialize = "auth/missing-verification-id")]
+    MissingVerificationId,
+    /// The user must complete sign-in with a second factor, using
+    /// [`get_multi_factor_resolver`].
+    #[strum(serialize = "auth/multi-factor-auth-required")]
+    MultiFactorAuthRequired,
+    /// The second factor does not belong to the user.
+    #[strum(serialize = "auth/multi-factor-info-not-found")]
+    MultiFactorInfoNotFound,
+    /// A network error occurred, such as a timeout or an unreachable host.
     #[strum(serialize = "auth/network-request-failed")]
     NetworkRequestFailed,
+    /// No popup or redirect event was found.
+    #[strum(serialize = "auth/no-auth-event")]
+    NoAuthEvent,
+    /// The user is not linked to the provider.
+    #[strum(serialize = "auth/no-such-provider")]
+    NoSuchProvider,
+    /// The operation requires a signed in user.
+    #[strum(serialize = "auth/null-user")]
+    NullUser,
+    /// The sign-in method is disabled for the project.
     #[strum(serialize = "auth/operation-not-allowed")]
     OperationNotAllowed,
+    /// The operation is not supported in the environment the app is running in.
+    #[strum(serialize = "auth/operation-not-supported-in-this-environment")]
+    OperationNotSupportedInThisEnvironment,
+    /// The password does not satisfy the project's password policy.
+    #[strum(serialize = "auth/password-does-not-meet-requirements")]
+    PasswordDoesNotMeetRequirements,
+    /// The browser blocked the popup.
+    #[strum(serialize = "auth/popup-blocked")]
+    PopupBlocked,
+    /// The user closed the popup before completing sign-in.
+    #[strum(serialize = "auth/popup-closed-by-user")]
+    PopupClosedByUser,
+    /// The user is already linked to an account of the provider.
+    #[strum(serialize = "auth/provider-already-linked")]
+    ProviderAlreadyLinked,
+    /// The project's quota for the operation was exceeded.
+    #[strum(serialize = "auth/quota-exceeded")]
+    QuotaExceeded,
+    /// reCAPTCHA Enterprise is not enabled for the project.
+    #[strum(serialize = "auth/recaptcha-not-enabled")]
+    RecaptchaNotEnabled,
+    /// The user cancelled the redirect.
+    #[strum(serialize = "auth/redirect-cancelled-by-user")]
+    RedirectCancelledByUser,
+    /// A redirect sign-in is already in progress.
+    #[strum(serialize = "auth/redirect-operation-pending")]
+    RedirectOperationPending,
+    /// The provider rejected the credential.
+    #[strum(serialize = "auth/rejected-credential")]
+    RejectedCredential,
+    /// The user last signed in too long ago to perform a sensitive
+    /// operation. See [`AuthError::requires_recent_login`].
     #[strum(serialize = "auth/requires-recent-login")]
     RequiresRecentLogin,
+    /// The second factor is already enrolled for the user.
+    #[strum(serialize = "auth/second-factor-already-in-use")]
+    SecondFactorAlreadyInUse,
+    /// The credential's tenant ID does not match the instance's.
+    #[strum(serialize = "auth/tenant-id-mismatch")]
+    TenantIdMismatch,
+    /// The operation timed out.
+    #[strum(serialize = "auth/timeout")]
+    Timeout,
+    /// Requests from the device were blocked due to unusual activity.
     #[strum(serialize = "auth/too-many-requests")]
     TooManyRequests,
+    /// The domain of the continue URL is not authorized.
+    #[strum(serialize = "auth/unauthorized-continue-uri")]
+    UnauthorizedContinueUri,
+    /// The domain is not authorized for OAuth operations.
     #[strum(serialize = "auth/unauthorized-domain")]
     UnauthorizedDomain,
+    /// The user's first factor does not support multi-factor authentication.
+    #[strum(serialize = "auth/unsupported-first-factor")]
+    UnsupportedFirstFactor,
+    /// The persistence type is not supported by the environment.
+    #[strum(serialize = "auth/unsupported-persistence-type")]
+    UnsupportedPersistenceType,
+    /// The operation is not supported for multi-tenant projects.
+    #[strum(serialize = "auth/unsupported-tenant-operation")]
+    UnsupportedTenantOperation,
+    /// The operation requires a verified email address.
+    #[strum(serialize = "auth/unverified-email")]
+    UnverifiedEmail,
+    /// The user denied the app's request for permissions.
+    #[strum(serialize = "auth/user-cancelled")]
+    UserCancelled,
+    /// The account was disabled by an administrator.
     #[strum(serialize = "auth/user-disabled")]
     UserDisabled,
+    /// The credential belongs to a different user.
+    #[strum(serialize = "auth/user-mismatch")]
+    UserMismatch,
+    /// No account exists for the identifier.
+    #[strum(serialize = "auth/user-not-found")]
+    UserNotFound,
+    /// The user signed out.
+    #[strum(serialize = "auth/user-signed-out")]
+    UserSignedOut,
+    /// The user's credential expired, and they must sign in again.
     #[strum(serialize = "auth/user-token-expired")]
     UserTokenExpired,
+    /// The password must be at least 6 characters long.
+    #[strum(serialize = "auth/weak-password")]
+    WeakPassword,
+    /// The browser does not support web storage, or it is disabled.
     #[strum(serialize = "auth/web-storage-unsupported")]
     WebStorageUnsupported,
-    #[strum(serialize = "auth/invalid-email")]
-    InvalidEmail,
-    #[strum(serialize = "auth/user-not-found")]
-    UserNotFound,
+    /// The password is incorrect.
     #[strum(serialize = "auth/wrong-password")]
     WrongPassword,
-    #[strum(serialize = "auth/email-already-in-use")]
-    EmailAlreadyInUse,
-    #[strum(serialize = "auth/weak-password")]
-    WeakPassword,
-    #[strum(serialize = "auth/missing-android-pkg-name")]
-    MissingAndroidPackageName,
-    #[strum(serialize = "auth/missing-continue-uri")]
-    MissingContinueUri,
-    #[strum(serialize = "auth/missing-ios-bundle-id")]
-    MissingIOSBundleId,
-    #[strum(serialize = "auth/invalid-continue-uri")]
-    InvalidContinueUri,
-    #[strum(serialize = "auth/unauthorized-continue-uri")]
-    UnauthorizedContinueUri,
-    #[strum(serialize = "auth/expired-action-code")]
-    ExpiredActionCode,
+    /// An error code not known to this crate.
     #[strum(default)]
     Other(String),
 }
 
+impl AuthErrorKind {
+    /// Whether the operation may succeed if retried later, without any
+    /// change in input from the user.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::InternalError
+                | Self::NetworkRequestFailed
+                | Self::QuotaExceeded
+                | Self::Timeout
+                | Self::TooManyRequests
+        )
+    }
+
+    /// Whether the error was caused by the user, such as by entering an
+    /// incorrect password or closing a popup, rather than by a
+    /// misconfiguration of the app or project.
+    ///
+    /// These errors are suitable to be shown to the user, see
+    /// [`AuthErrorKind::user_message`].
+    pub fn is_user_error(&self) -> bool {
+        matches!(
+            self,
+            Self::AccountExistsWithDifferentCredential
+                | Self::CancelledPopupRequest
+                | Self::CaptchaCheckFailed
+                | Self::CodeExpired
+                | Self::CredentialAlreadyInUse
+                | Self::EmailAlreadyInUse
+                | Self::EmailChangeNeedsVerification
+                | Self::ExpiredActionCode
+                | Self::InvalidActionCode
+                | Self::InvalidCredential
+                | Self::InvalidEmail
+                | Self::InvalidPhoneNumber
+                | Self::InvalidRecipientEmail
+                | Self::InvalidVerificationCode
+                | Self::MaximumSecondFactorCountExceeded
+                | Self::MissingPassword
+                | Self::MissingPhoneNumber
+                | Self::MissingVerificationCode
+                | Self::MultiFactorAuthRequired
+                | Self::PasswordDoesNotMeetRequirements
+                | Self::PopupBlocked
+                | Self::PopupClosedByUser
+                | Self::ProviderAlreadyLinked
+                | Self::RedirectCancelledByUser
+                | Self::RequiresRecentLogin
+                | Self::SecondFactorAlreadyInUse
+                | Self::UnverifiedEmail
+                | Self::UserCancelled
+                | Self::UserDisabled
+                | Self::UserMismatch
+                | Self::UserNotFound
+                | Self::WeakPassword
+                | Self::WrongPassword
+        )
+    }
+}
+
+#[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, TypedBuilder, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 #[builder(field_defaults(default))]
 pub struct ActionCodeSettings {
     pub android: Option<AndroidActionCodeSettings>,
     #[builder(setter(strip_option))]
     pub handle_code_in_app: Option<bool>,
+    #[serde(rename = "iOS")]
     pub ios: Option<IOSActionCodeSettings>,
     #[builder(!default)]
     pub url: String,
@@ -177,6 +569,120 @@ pub async fn send_password_reset_email(
         .map_err(|err| err.unchecked_into::<FirebaseError>().into())
 }
 
+/// Changes to a user's profile, for [`update_profile`].
+///
+/// Fields which are `None` are left unchanged, while fields which are
+/// `Some(None)` are cleared.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, TypedBuilder, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default))]
+pub struct ProfileUpdate {
+    #[builder(setter(transform = |display_name: impl Into<String>| Some(Some(display_name.into()))))]
+    pub display_name: Option<Option<String>>,
+    #[serde(rename = "photoURL")]
+    #[builder(setter(transform = |photo_url: impl Into<String>| Some(Some(photo_url.into()))))]
+    pub photo_url: Option<Option<String>>,
+}
+
+impl ProfileUpdate {
+    /// Removes the user's display name.
+    pub fn clear_display_name(mut self) -> Self {
+        self.display_name = Some(None);
+
+        self
+    }
+
+    /// Removes the user's photo URL.
+    pub fn clear_photo_url(mut self) -> Self {
+        self.photo_url = Some(None);
+
+        self
+    }
+}
+
+/// Updates the user's display name and/or photo URL.
+pub async fn update_profile(user: User, profile: ProfileUpdate) -> Result<(), AuthError> {
+    // The SDK only clears fields which are `null`, rather than `undefined`
+    let serializer = serde_wasm_bindgen::Serializer::new().serialize_missing_as_null(true);
+
+    let profile = serde::Serialize::serialize(&profile, &serializer).unwrap();
+
+    update_profile_js(user, profile)
+        .await
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Updates the user's email address without verifying it first.
+///
+/// Prefer [`verify_before_update_email`], as this fails if email
+/// enumeration protection is enabled for the project.
+pub async fn update_email(user: User, new_email: String) -> Result<(), AuthError> {
+    update_email_js(user, new_email)
+        .await
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+pub async fn update_password(user: User, new_password: String) -> Result<(), AuthError> {
+    update_password_js(user, new_password)
+        .await
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Sends a verification email to `new_email`, which becomes the user's
+/// email address once verified.
+pub async fn verify_before_update_email(
+    user: User,
+    new_email: String,
+    action_code_settings: Option<ActionCodeSettings>,
+) -> Result<(), AuthError> {
+    let action_code_settings = serde_wasm_bindgen::to_value(&action_code_settings).unwrap();
+
+    verify_before_update_email_js(user, new_email, action_code_settings)
+        .await
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+pub async fn send_email_verification(
+    user: User,
+    action_code_settings: Option<ActionCodeSettings>,
+) -> Result<(), AuthError> {
+    let action_code_settings = serde_wasm_bindgen::to_value(&action_code_settings).unwrap();
+
+    send_email_verification_js(user, action_code_settings)
+        .await
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Reauthenticates the user, which is required before sensitive operations
+/// when [`AuthError::requires_recent_login`] is `true`.
+pub async fn reauthenticate_with_credential(
+    user: User,
+    credential: &AuthCredential,
+) -> Result<UserCredential, AuthError> {
+    reauthenticate_with_credential_js(user, credential)
+        .await
+        .map(|cred| cred.unchecked_into::<UserCredential>())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Applies an email action code, such as one sent by
+/// [`send_email_verification`].
+pub async fn apply_action_code(auth: Auth, code: String) -> Result<(), AuthError> {
+    apply_action_code_js(auth, code)
+        .await
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Checks an email action code, returning information about the operation
+/// it was issued for without applying it.
+pub async fn check_action_code(auth: Auth, code: String) -> Result<ActionCodeInfo, AuthError> {
+    check_action_code_js(auth, code)
+        .await
+        .map(|info| info.unchecked_into::<ActionCodeInfo>())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
 pub async fn verify_password_reset_code(auth: Auth, code: String) -> Result<String, AuthError> {
     verify_password_reset_code_js(auth, code)
         .await
@@ -189,12 +695,287 @@ pub async fn confirm_password_reset(
     auth: Auth,
     code: String,
     new_password: String,
-) -> Result<(), JsValue> {
+) -> Result<(), AuthError> {
     confirm_password_reset_js(auth, code, new_password)
         .await
         .map_err(|err| err.unchecked_into::<FirebaseError>().into())
 }
 
+/// Signs in using a popup window for the given OAuth `provider`.
+pub async fn sign_in_with_popup(
+    auth: Auth,
+    provider: &AuthProvider,
+) -> Result<UserCredential, AuthError> {
+    sign_in_with_popup_js(auth, provider)
+        .await
+        .map(|cred| cred.unchecked_into::<UserCredential>())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Redirects the current page to the sign-in flow of the given OAuth
+/// `provider`. The result can be obtained with [`get_redirect_result`]
+/// once the user is redirected back.
+pub async fn sign_in_with_redirect(auth: Auth, provider: &AuthProvider) -> Result<(), AuthError> {
+    sign_in_with_redirect_js(auth, provider)
+        .await
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Returns the result of a redirect-based sign-in flow, or `None` if no
+/// redirect operation was performed.
+pub async fn get_redirect_result(auth: Auth) -> Result<Option<UserCredential>, AuthError> {
+    get_redirect_result_js(auth)
+        .await
+        .map(|cred| {
+            (!cred.is_null() && !cred.is_undefined())
+                .then(|| cred.unchecked_into::<UserCredential>())
+        })
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Signs in as a new anonymous user, which can later be upgraded to a
+/// permanent account with [`link_with_credential`] or [`link_with_popup`].
+pub async fn sign_in_anonymously(auth: Auth) -> Result<UserCredential, AuthError> {
+    sign_in_anonymously_js(auth)
+        .await
+        .map(|cred| cred.unchecked_into::<UserCredential>())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Signs in with a custom token minted by a backend using the Admin SDK.
+pub async fn sign_in_with_custom_token(
+    auth: Auth,
+    token: String,
+) -> Result<UserCredential, AuthError> {
+    sign_in_with_custom_token_js(auth, token)
+        .await
+        .map(|cred| cred.unchecked_into::<UserCredential>())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+pub async fn sign_in_with_credential(
+    auth: Auth,
+    credential: &AuthCredential,
+) -> Result<UserCredential, AuthError> {
+    sign_in_with_credential_js(auth, credential)
+        .await
+        .map(|cred| cred.unchecked_into::<UserCredential>())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Links `user` with the given `credential`.
+///
+/// Fails with [`AuthErrorKind::CredentialAlreadyInUse`] if the credential
+/// already belongs to another account, in which case that account can be
+/// signed into with [`sign_in_with_credential`] instead.
+pub async fn link_with_credential(
+    user: User,
+    credential: &AuthCredential,
+) -> Result<UserCredential, AuthError> {
+    link_with_credential_js(user, credential)
+        .await
+        .map(|cred| cred.unchecked_into::<UserCredential>())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Links `user` with the given OAuth `provider` using a popup window.
+pub async fn link_with_popup(
+    user: User,
+    provider: &AuthProvider,
+) -> Result<UserCredential, AuthError> {
+    link_with_popup_js(user, provider)
+        .await
+        .map(|cred| cred.unchecked_into::<UserCredential>())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Unlinks the provider with the given `provider_id` from `user`,
+/// returning the updated user.
+pub async fn unlink(user: User, provider_id: &str) -> Result<User, AuthError> {
+    unlink_js(user, provider_id)
+        .await
+        .map(|user| user.unchecked_into::<User>())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Sends a verification code to `phone_number`. Signing in is completed by
+/// calling [`ConfirmationResult::confirm`] with the code received by the user.
+pub async fn sign_in_with_phone_number(
+    auth: Auth,
+    phone_number: &str,
+    verifier: &ApplicationVerifier,
+) -> Result<ConfirmationResult, AuthError> {
+    sign_in_with_phone_number_js(auth, phone_number, verifier)
+        .await
+        .map(|res| res.unchecked_into::<ConfirmationResult>())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Links `user` with `phone_number`, once [`ConfirmationResult::confirm`] is
+/// called with the code received by the user.
+pub async fn link_with_phone_number(
+    user: User,
+    phone_number: &str,
+    verifier: &ApplicationVerifier,
+) -> Result<ConfirmationResult, AuthError> {
+    link_with_phone_number_js(user, phone_number, verifier)
+        .await
+        .map(|res| res.unchecked_into::<ConfirmationResult>())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+impl Auth {
+    /// Returns a stream of the signed-in user, which yields whenever the
+    /// user signs in or out.
+    pub fn auth_state_changes(&self) -> AuthStateStream {
+        AuthStateStream::new(|callback| on_auth_state_changed(self.clone(), callback))
+    }
+
+    /// Returns a stream of the signed-in user, which yields whenever the
+    /// user signs in or out, or their ID token is refreshed.
+    pub fn id_token_changes(&self) -> AuthStateStream {
+        AuthStateStream::new(|callback| on_id_token_changed(self.clone(), callback))
+    }
+
+    /// Returns a stream of the user about to be signed in, which yields
+    /// before the auth state is updated.
+    ///
+    /// The stream only observes changes, and cannot delay or abort them. Use
+    /// [`Auth::before_auth_state_change`] for that.
+    pub fn before_auth_state_changes(&self) -> AuthStateStream {
+        AuthStateStream::new(|callback| before_auth_state_changed(self.clone(), callback))
+    }
+
+    /// Registers `callback` to run with the user about to be signed in,
+    /// before the auth state is updated.
+    ///
+    /// The change waits for the returned future to complete. Returning an
+    /// error aborts the change, and the sign-in or sign-out which caused it
+    /// fails with that error. `on_abort` is called if a callback registered
+    /// later aborts the change after this one completed, so that its side
+    /// effects can be undone.
+    ///
+    /// The callback is unregistered when the returned listener is dropped.
+    pub fn before_auth_state_change<F, Fut>(
+        &self,
+        mut callback: F,
+        on_abort: Option<Box<dyn FnMut()>>,
+    ) -> BeforeAuthStateListener
+    where
+        F: FnMut(Option<User>) -> Fut + 'static,
+        Fut: Future<Output = Result<(), JsValue>> + 'static,
+    {
+        let callback = Closure::new(move |user| {
+            let fut = callback(user);
+
+            wasm_bindgen_futures::future_to_promise(
+                async move { fut.await.map(|_| JsValue::UNDEFINED) },
+            )
+        });
+        let on_abort = on_abort.map(Closure::new);
+
+        let unsub =
+            before_auth_state_changed_blocking_js(self.clone(), &callback, on_abort.as_ref());
+
+        BeforeAuthStateListener {
+            _callback: callback,
+            _on_abort: on_abort,
+            unsub,
+        }
+    }
+
+    /// Waits for the initial auth state to be determined, such as after
+    /// restoring a persisted session, returning the signed-in user, or
+    /// `None` if no user is signed in.
+    pub async fn authenticated_user(&self) -> Option<User> {
+        self.auth_state_changes()
+            .next()
+            .await
+            .expect("auth state stream to never end")
+    }
+
+    /// Waits until a user is signed in, returning immediately if one already
+    /// is.
+    pub async fn wait_for_sign_in(&self) -> User {
+        self.auth_state_changes()
+            .filter_map(futures::future::ready)
+            .next()
+            .await
+            .expect("auth state stream to never end")
+    }
+}
+
+/// A callback registered with [`Auth::before_auth_state_change`], which is
+/// unregistered when this is dropped.
+pub struct BeforeAuthStateListener {
+    _callback: Closure<dyn FnMut(Option<User>) -> js_sys::Promise>,
+    _on_abort: Option<Closure<dyn FnMut()>>,
+    unsub: js_sys::Function,
+}
+
+impl Drop for BeforeAuthStateListener {
+    fn drop(&mut self) {
+        self.unsub.call0(&JsValue::UNDEFINED).unwrap();
+    }
+}
+
+/// A [`Stream`] of auth state changes, created by
+/// [`Auth::auth_state_changes`], [`Auth::id_token_changes`] or
+/// [`Auth::before_auth_state_changes`].
+///
+/// The underlying listener is unsubscribed when this is dropped.
+pub struct AuthStateStream {
+    _on_change: Closure<dyn FnMut(Option<User>)>,
+    users: Rc<RefCell<VecDeque<Option<User>>>>,
+    waker: Rc<RefCell<Option<Waker>>>,
+    unsub: js_sys::Function,
+}
+
+impl AuthStateStream {
+    fn new(subscribe: impl FnOnce(&Closure<dyn FnMut(Option<User>)>) -> js_sys::Function) -> Self {
+        let users: Rc<RefCell<VecDeque<Option<User>>>> = Rc::default();
+        let waker: Rc<RefCell<Option<Waker>>> = Rc::default();
+
+        let on_change = Closure::new(clone!([users, waker], move |user| {
+            users.borrow_mut().push_back(user);
+
+            if let Some(w) = waker.borrow().as_ref() {
+                w.wake_by_ref();
+            }
+        }));
+
+        let unsub = subscribe(&on_change);
+
+        Self {
+            _on_change: on_change,
+            users,
+            waker,
+            unsub,
+        }
+    }
+}
+
+impl Drop for AuthStateStream {
+    fn drop(&mut self) {
+        self.unsub.call0(&JsValue::UNDEFINED).unwrap();
+    }
+}
+
+impl Stream for AuthStateStream {
+    type Item = Option<User>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Update waker
+        *self.waker.borrow_mut() = Some(cx.waker().to_owned());
+
+        if let Some(user) = self.users.borrow_mut().pop_front() {
+            Poll::Ready(Some(user))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
 #[wasm_bindgen_struct]
 #[opts(module = "firebase/auth", getter)]
 #[derive(Clone, Debug)]
@@ -212,8 +993,34 @@ extern "C" {
     #[wasm_bindgen(js_name = getAuth)]
     pub fn get_auth() -> Auth;
 
+    /// The currently signed-in user, or `None` if no user is signed in.
+    #[wasm_bindgen(method, getter, js_name = currentUser)]
+    pub fn current_user(this: &Auth) -> Option<User>;
+
     #[wasm_bindgen(js_name = onAuthStateChanged)]
-    pub fn on_auth_state_changed(auth: Auth, callback: &Closure<dyn FnMut(Option<User>)>);
+    pub fn on_auth_state_changed(
+        auth: Auth,
+        callback: &Closure<dyn FnMut(Option<User>)>,
+    ) -> js_sys::Function;
+
+    #[wasm_bindgen(js_name = onIdTokenChanged)]
+    pub fn on_id_token_changed(
+        auth: Auth,
+        callback: &Closure<dyn FnMut(Option<User>)>,
+    ) -> js_sys::Function;
+
+    #[wasm_bindgen(js_name = beforeAuthStateChanged)]
+    pub fn before_auth_state_changed(
+        auth: Auth,
+        callback: &Closure<dyn FnMut(Option<User>)>,
+    ) -> js_sys::Function;
+
+    #[wasm_bindgen(js_name = beforeAuthStateChanged)]
+    fn before_auth_state_changed_blocking_js(
+        auth: Auth,
+        callback: &Closure<dyn FnMut(Option<User>) -> js_sys::Promise>,
+        on_abort: Option<&Closure<dyn FnMut()>>,
+    ) -> js_sys::Function;
 
     #[wasm_bindgen(js_name = createUserWithEmailAndPassword, catch)]
     async fn create_user_with_email_and_password_js(
@@ -259,6 +1066,88 @@ extern "C" {
     #[wasm_bindgen(js_name = verifyPasswordResetCode, catch)]
     async fn verify_password_reset_code_js(auth: Auth, code: String) -> Result<JsValue, JsValue>;
 
+    #[wasm_bindgen(js_name = signInWithPopup, catch)]
+    async fn sign_in_with_popup_js(auth: Auth, provider: &AuthProvider)
+        -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = signInWithRedirect, catch)]
+    async fn sign_in_with_redirect_js(auth: Auth, provider: &AuthProvider) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_name = getRedirectResult, catch)]
+    async fn get_redirect_result_js(auth: Auth) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = signInAnonymously, catch)]
+    async fn sign_in_anonymously_js(auth: Auth) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = signInWithCustomToken, catch)]
+    async fn sign_in_with_custom_token_js(auth: Auth, token: String) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = signInWithCredential, catch)]
+    async fn sign_in_with_credential_js(
+        auth: Auth,
+        credential: &AuthCredential,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = linkWithCredential, catch)]
+    async fn link_with_credential_js(
+        user: User,
+        credential: &AuthCredential,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = linkWithPopup, catch)]
+    async fn link_with_popup_js(user: User, provider: &AuthProvider) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = unlink, catch)]
+    async fn unlink_js(user: User, provider_id: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = signInWithPhoneNumber, catch)]
+    async fn sign_in_with_phone_number_js(
+        auth: Auth,
+        phone_number: &str,
+        verifier: &ApplicationVerifier,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = linkWithPhoneNumber, catch)]
+    async fn link_with_phone_number_js(
+        user: User,
+        phone_number: &str,
+        verifier: &ApplicationVerifier,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = updateProfile, catch)]
+    async fn update_profile_js(user: User, profile: JsValue) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_name = updateEmail, catch)]
+    async fn update_email_js(user: User, new_email: String) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_name = updatePassword, catch)]
+    async fn update_password_js(user: User, new_password: String) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_name = verifyBeforeUpdateEmail, catch)]
+    async fn verify_before_update_email_js(
+        user: User,
+        new_email: String,
+        action_code_settings: JsValue,
+    ) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_name = sendEmailVerification, catch)]
+    async fn send_email_verification_js(
+        user: User,
+        action_code_settings: JsValue,
+    ) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_name = reauthenticateWithCredential, catch)]
+    async fn reauthenticate_with_credential_js(
+        user: User,
+        credential: &AuthCredential,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = applyActionCode, catch)]
+    async fn apply_action_code_js(auth: Auth, code: String) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_name = checkActionCode, catch)]
+    async fn check_action_code_js(auth: Auth, code: String) -> Result<JsValue, JsValue>;
+
     #[wasm_bindgen(js_name = confirmPasswordReset, catch)]
     async fn confirm_password_reset_js(
         auth: Auth,
@@ -0,0 +1,102 @@
+use super::MultiFactorInfo;
+use wasm_bindgen::prelude::*;
+
+/// The operation an email action code was issued for.
+#[derive(Clone, Debug, PartialEq, Eq, strum::EnumString, strum::Display)]
+#[non_exhaustive]
+pub enum ActionCodeOperation {
+    #[strum(serialize = "EMAIL_SIGNIN")]
+    EmailSignIn,
+    #[strum(serialize = "PASSWORD_RESET")]
+    PasswordReset,
+    #[strum(serialize = "RECOVER_EMAIL")]
+    RecoverEmail,
+    #[strum(serialize = "REVERT_SECOND_FACTOR_ADDITION")]
+    RevertSecondFactorAddition,
+    #[strum(serialize = "VERIFY_AND_CHANGE_EMAIL")]
+    VerifyAndChangeEmail,
+    #[strum(serialize = "VERIFY_EMAIL")]
+    VerifyEmail,
+    #[strum(default)]
+    Other(String),
+}
+
+impl ActionCodeInfo {
+    pub fn operation_kind(&self) -> ActionCodeOperation {
+        self.operation().parse().unwrap()
+    }
+}
+
+impl ActionCodeURL {
+    pub fn operation_kind(&self) -> ActionCodeOperation {
+        self.operation().parse().unwrap()
+    }
+}
+
+#[wasm_bindgen(module = "firebase/auth")]
+extern "C" {
+    #[derive(Clone, Debug)]
+    pub type ActionCodeInfo;
+    #[derive(Clone, Debug)]
+    pub type ActionCodeData;
+    #[derive(Clone, Debug)]
+    pub type ActionCodeURL;
+
+    // =========================================================================
+    //                            ActionCodeInfo
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter)]
+    pub fn data(this: &ActionCodeInfo) -> ActionCodeData;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn operation(this: &ActionCodeInfo) -> String;
+
+    // =========================================================================
+    //                            ActionCodeData
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter)]
+    pub fn email(this: &ActionCodeData) -> Option<String>;
+
+    /// The email address the user had before the change, for
+    /// [`ActionCodeOperation::RecoverEmail`] and
+    /// [`ActionCodeOperation::VerifyAndChangeEmail`].
+    #[wasm_bindgen(method, getter, js_name = previousEmail)]
+    pub fn previous_email(this: &ActionCodeData) -> Option<String>;
+
+    /// The second factor which was added, for
+    /// [`ActionCodeOperation::RevertSecondFactorAddition`].
+    #[wasm_bindgen(method, getter, js_name = multiFactorInfo)]
+    pub fn multi_factor_info(this: &ActionCodeData) -> Option<MultiFactorInfo>;
+
+    // =========================================================================
+    //                            ActionCodeURL
+    // =========================================================================
+
+    /// Parses an email action link, returning `None` if it is invalid.
+    #[wasm_bindgen(static_method_of = ActionCodeURL, js_name = parseLink)]
+    pub fn parse_link(link: &str) -> Option<ActionCodeURL>;
+
+    #[wasm_bindgen(method, getter, js_name = apiKey)]
+    pub fn api_key(this: &ActionCodeURL) -> String;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn code(this: &ActionCodeURL) -> String;
+
+    #[wasm_bindgen(method, getter, js_name = continueUrl)]
+    pub fn continue_url(this: &ActionCodeURL) -> Option<String>;
+
+    #[wasm_bindgen(method, getter, js_name = languageCode)]
+    pub fn language_code(this: &ActionCodeURL) -> Option<String>;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn operation(this: &ActionCodeURL) -> String;
+
+    #[wasm_bindgen(method, getter, js_name = tenantId)]
+    pub fn tenant_id(this: &ActionCodeURL) -> Option<String>;
+
+    /// Parses an email action link, returning `None` if it is invalid.
+    #[wasm_bindgen(js_name = parseActionCodeURL)]
+    pub fn parse_action_code_url(link: &str) -> Option<ActionCodeURL>;
+}
@@ -0,0 +1,145 @@
+use super::{Auth, AuthError};
+use crate::{FirebaseApp, FirebaseError};
+use wasm_bindgen::prelude::*;
+
+/// Where the signed-in user is persisted between page loads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Persistence {
+    /// Persisted in `localStorage`, surviving browser restarts.
+    Local,
+    /// Persisted in `sessionStorage`, cleared when the tab is closed.
+    Session,
+    /// Persisted in IndexedDB, surviving browser restarts. This is the
+    /// default.
+    IndexedDbLocal,
+    /// Not persisted, signing the user out on page reload.
+    InMemory,
+}
+
+impl Persistence {
+    fn to_js(self) -> JsPersistence {
+        match self {
+            Self::Local => BROWSER_LOCAL_PERSISTENCE.with(JsPersistence::clone),
+            Self::Session => BROWSER_SESSION_PERSISTENCE.with(JsPersistence::clone),
+            Self::IndexedDbLocal => INDEXED_DB_LOCAL_PERSISTENCE.with(JsPersistence::clone),
+            Self::InMemory => IN_MEMORY_PERSISTENCE.with(JsPersistence::clone),
+        }
+    }
+}
+
+/// Handles the popup and redirect sign-in flows, such as
+/// [`sign_in_with_popup`](super::sign_in_with_popup).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopupRedirectResolver {
+    Browser,
+}
+
+#[derive(Debug, Clone, Default, TypedBuilder)]
+#[builder(field_defaults(default))]
+pub struct Dependencies {
+    /// Persistence mechanisms to try, in order of preference.
+    #[builder(setter(into))]
+    pub persistence: Vec<Persistence>,
+    /// Must be set to use the popup and redirect sign-in flows.
+    #[builder(setter(strip_option))]
+    pub popup_redirect_resolver: Option<PopupRedirectResolver>,
+}
+
+/// Initializes [`Auth`] for `app` with the given `deps`, which allows
+/// excluding unused dependencies from the bundle, as opposed to
+/// [`get_auth`](super::get_auth).
+///
+/// Must be called at most once per app, before any call to
+/// [`get_auth`](super::get_auth).
+pub fn initialize_auth(app: &FirebaseApp, deps: Dependencies) -> Result<Auth, AuthError> {
+    let Dependencies {
+        persistence,
+        popup_redirect_resolver,
+    } = deps;
+
+    let js_deps = js_sys::Object::new();
+
+    if !persistence.is_empty() {
+        let persistence = persistence
+            .into_iter()
+            .map(Persistence::to_js)
+            .collect::<js_sys::Array>();
+
+        js_sys::Reflect::set(&js_deps, &"persistence".into(), &persistence).unwrap();
+    }
+
+    if let Some(PopupRedirectResolver::Browser) = popup_redirect_resolver {
+        BROWSER_POPUP_REDIRECT_RESOLVER.with(|resolver| {
+            js_sys::Reflect::set(&js_deps, &"popupRedirectResolver".into(), resolver).unwrap();
+        });
+    }
+
+    initialize_auth_js(app, &js_deps).map_err(Into::into)
+}
+
+/// Changes how the signed-in user is persisted, including for the current
+/// user.
+pub async fn set_persistence(auth: Auth, persistence: Persistence) -> Result<(), AuthError> {
+    set_persistence_js(auth, &persistence.to_js())
+        .await
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+#[wasm_bindgen(module = "firebase/auth")]
+extern "C" {
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(js_name = Persistence)]
+    type JsPersistence;
+
+    #[wasm_bindgen(thread_local_v2, js_name = browserLocalPersistence)]
+    static BROWSER_LOCAL_PERSISTENCE: JsPersistence;
+
+    #[wasm_bindgen(thread_local_v2, js_name = browserSessionPersistence)]
+    static BROWSER_SESSION_PERSISTENCE: JsPersistence;
+
+    #[wasm_bindgen(thread_local_v2, js_name = indexedDBLocalPersistence)]
+    static INDEXED_DB_LOCAL_PERSISTENCE: JsPersistence;
+
+    #[wasm_bindgen(thread_local_v2, js_name = inMemoryPersistence)]
+    static IN_MEMORY_PERSISTENCE: JsPersistence;
+
+    #[wasm_bindgen(thread_local_v2, js_name = browserPopupRedirectResolver)]
+    static BROWSER_POPUP_REDIRECT_RESOLVER: JsValue;
+
+    #[wasm_bindgen(js_name = initializeAuth, catch)]
+    fn initialize_auth_js(app: &FirebaseApp, deps: &js_sys::Object) -> Result<Auth, FirebaseError>;
+
+    #[wasm_bindgen(js_name = setPersistence, catch)]
+    async fn set_persistence_js(auth: Auth, persistence: &JsPersistence) -> Result<(), JsValue>;
+
+    /// Sets the language code to the browser's preferred language.
+    #[wasm_bindgen(js_name = useDeviceLanguage)]
+    fn use_device_language_js(auth: &Auth);
+
+    // =========================================================================
+    //                            Auth
+    // =========================================================================
+
+    /// The language used for emails, SMS messages and reCAPTCHA widgets sent
+    /// or rendered by the SDK. Defaults to the project's language.
+    #[wasm_bindgen(method, getter, js_name = languageCode)]
+    pub fn language_code(this: &Auth) -> Option<String>;
+
+    #[wasm_bindgen(method, setter, js_name = languageCode)]
+    pub fn set_language_code(this: &Auth, language_code: Option<&str>);
+
+    /// The Identity Platform tenant users are signed into, or `None` for the
+    /// project-level tenant.
+    #[wasm_bindgen(method, getter, js_name = tenantId)]
+    pub fn tenant_id(this: &Auth) -> Option<String>;
+
+    #[wasm_bindgen(method, setter, js_name = tenantId)]
+    pub fn set_tenant_id(this: &Auth, tenant_id: Option<&str>);
+}
+
+impl Auth {
+    /// Sets [`Auth::language_code`] to the browser's preferred language.
+    pub fn use_device_language(&self) {
+        use_device_language_js(self);
+    }
+}
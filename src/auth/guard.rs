@@ -0,0 +1,98 @@
+use super::{Auth, AuthError, User};
+use wasm_bindgen::JsValue;
+
+/// Checks whether a user has a role, as granted by a custom claim on their
+/// ID token.
+///
+/// The claim is satisfied if it is `true`, such as `{ admin: true }`, or if
+/// it is a string or array of strings containing one of the guard's
+/// roles, such as `{ role: "editor" }` or `{ roles: ["editor"] }`.
+///
+/// Custom claims are only updated when the ID token is refreshed, so a
+/// role which was just granted may not be visible until then, unless
+/// [`RoleGuard::force_refresh`] is set.
+#[derive(Clone, Debug)]
+pub struct RoleGuard {
+    claim: String,
+    roles: Vec<String>,
+    force_refresh: bool,
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum RoleGuardError {
+    #[error("no user is signed in")]
+    SignedOut,
+    #[error("the user does not have the required role")]
+    Forbidden(User),
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+}
+
+impl RoleGuard {
+    /// Creates a guard checking the custom claim named `claim`.
+    pub fn new(claim: impl Into<String>) -> Self {
+        Self {
+            claim: claim.into(),
+            roles: vec![],
+            force_refresh: false,
+        }
+    }
+
+    /// Allows users whose claim contains `role`.
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.roles.push(role.into());
+
+        self
+    }
+
+    /// Whether to refresh the ID token before checking its claims.
+    pub fn force_refresh(mut self, force_refresh: bool) -> Self {
+        self.force_refresh = force_refresh;
+
+        self
+    }
+
+    /// Returns whether `user` satisfies the guard.
+    pub async fn check(&self, user: &User) -> Result<bool, AuthError> {
+        let claims = user.get_id_token_result(self.force_refresh).await?.claims();
+
+        let value = js_sys::Reflect::get(&claims, &self.claim.as_str().into())
+            .unwrap_or(JsValue::UNDEFINED);
+
+        Ok(self.is_satisfied_by(&value))
+    }
+
+    /// Waits for the initial auth state to be determined, returning the
+    /// signed-in user if they satisfy the guard.
+    pub async fn require(&self, auth: &Auth) -> Result<User, RoleGuardError> {
+        let user = auth
+            .authenticated_user()
+            .await
+            .ok_or(RoleGuardError::SignedOut)?;
+
+        if self.check(&user).await? {
+            Ok(user)
+        } else {
+            Err(RoleGuardError::Forbidden(user))
+        }
+    }
+
+    fn is_satisfied_by(&self, value: &JsValue) -> bool {
+        if value.as_bool() == Some(true) {
+            return true;
+        }
+
+        if let Some(role) = value.as_string() {
+            return self.roles.contains(&role);
+        }
+
+        if js_sys::Array::is_array(value) {
+            return js_sys::Array::from(value)
+                .iter()
+                .filter_map(|role| role.as_string())
+                .any(|role| self.roles.contains(&role));
+        }
+
+        false
+    }
+}
@@ -0,0 +1,92 @@
+use super::{AuthError, AuthErrorKind};
+use std::borrow::Cow;
+
+/// Provides user-facing messages for [`AuthErrorKind`]s, such as to
+/// translate them for display in login forms.
+///
+/// Returning `None` falls back to the English message from
+/// [`AuthErrorKind::user_message`].
+pub trait AuthErrorMessages {
+    fn message(&self, kind: &AuthErrorKind) -> Option<Cow<'static, str>>;
+}
+
+/// The default English [`AuthErrorMessages`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct English;
+
+impl AuthErrorMessages for English {
+    fn message(&self, kind: &AuthErrorKind) -> Option<Cow<'static, str>> {
+        Some(kind.user_message().into())
+    }
+}
+
+impl AuthErrorKind {
+    /// A message describing the error in English, suitable to be shown to
+    /// the user.
+    ///
+    /// Errors which the user cannot act upon share a generic message.
+    pub fn user_message(&self) -> &'static str {
+        use AuthErrorKind::*;
+
+        match self {
+            AccountExistsWithDifferentCredential => {
+                "An account already exists with this email address. Sign in using \
+                 a different method to link your accounts."
+            }
+            CancelledPopupRequest | PopupClosedByUser | RedirectCancelledByUser | UserCancelled => {
+                "Sign-in was cancelled."
+            }
+            CaptchaCheckFailed => "The reCAPTCHA verification failed. Please try again.",
+            CodeExpired => "The verification code has expired. Please request a new one.",
+            CredentialAlreadyInUse => "This account is already linked to a different user.",
+            EmailAlreadyInUse => "An account already exists with this email address.",
+            EmailChangeNeedsVerification => {
+                "Please verify your new email address before changing it."
+            }
+            ExpiredActionCode => "This link has expired. Please request a new one.",
+            InvalidActionCode => "This link is invalid or has already been used.",
+            InvalidCredential | WrongPassword => "The email address or password is incorrect.",
+            InvalidEmail => "Please enter a valid email address.",
+            InvalidPhoneNumber => "Please enter a valid phone number.",
+            InvalidRecipientEmail => "The email could not be delivered to this address.",
+            InvalidVerificationCode => "The verification code is incorrect.",
+            MaximumSecondFactorCountExceeded => {
+                "You have already enrolled the maximum number of second factors."
+            }
+            MissingPassword => "Please enter your password.",
+            MissingPhoneNumber => "Please enter your phone number.",
+            MissingVerificationCode => "Please enter the verification code.",
+            MultiFactorAuthRequired => "Please verify your identity with your second factor.",
+            NetworkRequestFailed => "Unable to connect. Please check your internet connection.",
+            PasswordDoesNotMeetRequirements => "The password does not meet the requirements.",
+            PopupBlocked => "The sign-in popup was blocked. Please allow popups and try again.",
+            ProviderAlreadyLinked => "This sign-in method is already linked to your account.",
+            QuotaExceeded | TooManyRequests => "Too many attempts. Please try again later.",
+            RequiresRecentLogin => "Please sign in again to continue.",
+            SecondFactorAlreadyInUse => "This second factor is already enrolled.",
+            Timeout => "The request timed out. Please try again.",
+            UnverifiedEmail => "Please verify your email address to continue.",
+            UserDisabled => "This account has been disabled.",
+            UserMismatch => "These credentials belong to a different account.",
+            UserNotFound => "No account exists with these details.",
+            WeakPassword => "The password must be at least 6 characters long.",
+            _ => "Something went wrong. Please try again.",
+        }
+    }
+}
+
+impl AuthError {
+    /// A message describing the error in English, suitable to be shown to
+    /// the user.
+    pub fn user_message(&self) -> &'static str {
+        self.kind.user_message()
+    }
+
+    /// A message describing the error, provided by `messages`, suitable to be
+    /// shown to the user.
+    pub fn localized_message(&self, messages: &impl AuthErrorMessages) -> Cow<'static, str> {
+        messages
+            .message(&self.kind)
+            .unwrap_or_else(|| self.kind.user_message().into())
+    }
+}
@@ -0,0 +1,236 @@
+use super::{Auth, AuthError, PhoneAuthCredential, User, UserCredential};
+use crate::FirebaseError;
+use wasm_bindgen::{prelude::*, JsCast};
+
+/// The kind of an enrolled second factor.
+#[derive(Clone, Debug, PartialEq, Eq, strum::EnumString, strum::Display)]
+#[non_exhaustive]
+pub enum FactorId {
+    #[strum(serialize = "phone")]
+    Phone,
+    #[strum(serialize = "totp")]
+    Totp,
+    #[strum(default)]
+    Other(String),
+}
+
+/// Returns the [`MultiFactorUser`] for `user`, used to manage its second
+/// factors.
+pub fn multi_factor(user: User) -> MultiFactorUser {
+    multi_factor_js(user)
+}
+
+/// Returns a [`MultiFactorResolver`] for completing a sign-in which failed
+/// with [`AuthErrorKind::MultiFactorAuthRequired`](super::AuthErrorKind::MultiFactorAuthRequired).
+pub fn get_multi_factor_resolver(
+    auth: Auth,
+    error: &AuthError,
+) -> Result<MultiFactorResolver, AuthError> {
+    get_multi_factor_resolver_js(auth, &error.source).map_err(Into::into)
+}
+
+impl MultiFactorInfo {
+    pub fn factor(&self) -> FactorId {
+        self.factor_id().parse().unwrap()
+    }
+}
+
+impl MultiFactorUser {
+    pub async fn get_session(&self) -> Result<MultiFactorSession, AuthError> {
+        self.get_session_js()
+            .await
+            .map(|session| session.unchecked_into::<MultiFactorSession>())
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+
+    /// Enrolls a second factor, identified in the UI by `display_name`.
+    pub async fn enroll(
+        &self,
+        assertion: &MultiFactorAssertion,
+        display_name: Option<&str>,
+    ) -> Result<(), AuthError> {
+        self.enroll_js(assertion, display_name)
+            .await
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+
+    pub async fn unenroll(&self, factor: &MultiFactorInfo) -> Result<(), AuthError> {
+        self.unenroll_js(factor)
+            .await
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+}
+
+impl MultiFactorResolver {
+    pub async fn resolve_sign_in(
+        &self,
+        assertion: &MultiFactorAssertion,
+    ) -> Result<UserCredential, AuthError> {
+        self.resolve_sign_in_js(assertion)
+            .await
+            .map(|cred| cred.unchecked_into::<UserCredential>())
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+}
+
+impl TotpMultiFactorGenerator {
+    /// Generates a new TOTP secret to be added to an authenticator app,
+    /// using a session obtained from [`MultiFactorUser::get_session`].
+    pub async fn generate_secret(session: &MultiFactorSession) -> Result<TotpSecret, AuthError> {
+        Self::generate_secret_js(session)
+            .await
+            .map(|secret| secret.unchecked_into::<TotpSecret>())
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+}
+
+#[wasm_bindgen(module = "firebase/auth")]
+extern "C" {
+    #[derive(Clone, Debug)]
+    pub type MultiFactorUser;
+    #[derive(Clone, Debug)]
+    pub type MultiFactorSession;
+    #[derive(Clone, Debug)]
+    pub type MultiFactorResolver;
+    #[derive(Clone, Debug)]
+    pub type MultiFactorInfo;
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(extends = MultiFactorInfo)]
+    pub type PhoneMultiFactorInfo;
+    #[derive(Clone, Debug)]
+    pub type MultiFactorAssertion;
+    #[derive(Clone, Debug)]
+    pub type TotpMultiFactorGenerator;
+    #[derive(Clone, Debug)]
+    pub type TotpSecret;
+    #[derive(Clone, Debug)]
+    pub type PhoneMultiFactorGenerator;
+
+    #[wasm_bindgen(js_name = multiFactor)]
+    fn multi_factor_js(user: User) -> MultiFactorUser;
+
+    #[wasm_bindgen(js_name = getMultiFactorResolver, catch)]
+    fn get_multi_factor_resolver_js(
+        auth: Auth,
+        error: &FirebaseError,
+    ) -> Result<MultiFactorResolver, FirebaseError>;
+
+    // =========================================================================
+    //                            MultiFactorUser
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter, js_name = enrolledFactors)]
+    pub fn enrolled_factors(this: &MultiFactorUser) -> Vec<MultiFactorInfo>;
+
+    #[wasm_bindgen(method, js_name = getSession, catch)]
+    async fn get_session_js(this: &MultiFactorUser) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(method, js_name = enroll, catch)]
+    async fn enroll_js(
+        this: &MultiFactorUser,
+        assertion: &MultiFactorAssertion,
+        display_name: Option<&str>,
+    ) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(method, js_name = unenroll, catch)]
+    async fn unenroll_js(this: &MultiFactorUser, factor: &MultiFactorInfo) -> Result<(), JsValue>;
+
+    // =========================================================================
+    //                            MultiFactorResolver
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter)]
+    pub fn hints(this: &MultiFactorResolver) -> Vec<MultiFactorInfo>;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn session(this: &MultiFactorResolver) -> MultiFactorSession;
+
+    #[wasm_bindgen(method, js_name = resolveSignIn, catch)]
+    async fn resolve_sign_in_js(
+        this: &MultiFactorResolver,
+        assertion: &MultiFactorAssertion,
+    ) -> Result<JsValue, JsValue>;
+
+    // =========================================================================
+    //                            MultiFactorInfo
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter)]
+    pub fn uid(this: &MultiFactorInfo) -> String;
+
+    #[wasm_bindgen(method, getter, js_name = displayName)]
+    pub fn display_name(this: &MultiFactorInfo) -> Option<String>;
+
+    #[wasm_bindgen(method, getter, js_name = enrollmentTime)]
+    pub fn enrollment_time(this: &MultiFactorInfo) -> String;
+
+    #[wasm_bindgen(method, getter, js_name = factorId)]
+    pub fn factor_id(this: &MultiFactorInfo) -> String;
+
+    #[wasm_bindgen(method, getter, js_name = phoneNumber)]
+    pub fn phone_number(this: &PhoneMultiFactorInfo) -> String;
+
+    // =========================================================================
+    //                            MultiFactorAssertion
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter, js_name = factorId)]
+    pub fn factor_id(this: &MultiFactorAssertion) -> String;
+
+    // =========================================================================
+    //                            TotpMultiFactorGenerator
+    // =========================================================================
+
+    #[wasm_bindgen(static_method_of = TotpMultiFactorGenerator, js_name = generateSecret, catch)]
+    async fn generate_secret_js(session: &MultiFactorSession) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(static_method_of = TotpMultiFactorGenerator, js_name = assertionForEnrollment)]
+    pub fn assertion_for_enrollment(
+        secret: &TotpSecret,
+        one_time_password: &str,
+    ) -> MultiFactorAssertion;
+
+    #[wasm_bindgen(static_method_of = TotpMultiFactorGenerator, js_name = assertionForSignIn)]
+    pub fn assertion_for_sign_in(
+        enrollment_id: &str,
+        one_time_password: &str,
+    ) -> MultiFactorAssertion;
+
+    // =========================================================================
+    //                            TotpSecret
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter, js_name = secretKey)]
+    pub fn secret_key(this: &TotpSecret) -> String;
+
+    #[wasm_bindgen(method, getter, js_name = hashingAlgorithm)]
+    pub fn hashing_algorithm(this: &TotpSecret) -> String;
+
+    #[wasm_bindgen(method, getter, js_name = codeLength)]
+    pub fn code_length(this: &TotpSecret) -> u32;
+
+    #[wasm_bindgen(method, getter, js_name = codeIntervalSeconds)]
+    pub fn code_interval_seconds(this: &TotpSecret) -> u32;
+
+    #[wasm_bindgen(method, getter, js_name = enrollmentCompletionDeadline)]
+    pub fn enrollment_completion_deadline(this: &TotpSecret) -> String;
+
+    /// Returns an `otpauth://` URL, to be displayed as a QR code which can be
+    /// scanned by an authenticator app.
+    #[wasm_bindgen(method, js_name = generateQrCodeUrl)]
+    pub fn generate_qr_code_url(
+        this: &TotpSecret,
+        account_name: Option<&str>,
+        issuer: Option<&str>,
+    ) -> String;
+
+    #[wasm_bindgen(method, js_name = openInOtpApp)]
+    pub fn open_in_otp_app(this: &TotpSecret, qr_code_url: &str);
+
+    // =========================================================================
+    //                            PhoneMultiFactorGenerator
+    // =========================================================================
+
+    #[wasm_bindgen(static_method_of = PhoneMultiFactorGenerator, js_name = assertion)]
+    pub fn assertion(credential: &PhoneAuthCredential) -> MultiFactorAssertion;
+}
@@ -0,0 +1,292 @@
+use super::{
+    Auth, AuthCredential, AuthError, AuthProvider, MultiFactorInfo, MultiFactorSession,
+    UserCredential,
+};
+use crate::FirebaseError;
+use std::ops::Deref;
+use wasm_bindgen::{prelude::*, JsCast};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum RecaptchaSize {
+    /// The widget is hidden, and the challenge is triggered by calling
+    /// [`RecaptchaVerifier::verify`] or by submitting the bound button.
+    Invisible,
+    #[default]
+    Normal,
+    Compact,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum RecaptchaTheme {
+    #[default]
+    Light,
+    Dark,
+}
+
+#[derive(TypedBuilder)]
+#[builder(field_defaults(default))]
+pub struct RecaptchaParameters {
+    pub size: RecaptchaSize,
+    pub theme: RecaptchaTheme,
+    /// Called with the reCAPTCHA response token once the user solves the
+    /// challenge.
+    #[builder(setter(transform = |f: impl FnMut(String) + 'static| Some(Box::new(f) as Box<dyn FnMut(String)>)))]
+    pub callback: Option<Box<dyn FnMut(String)>>,
+    /// Called when the reCAPTCHA response expires and the user must solve a
+    /// new challenge.
+    #[builder(setter(transform = |f: impl FnMut() + 'static| Some(Box::new(f) as Box<dyn FnMut()>)))]
+    pub expired_callback: Option<Box<dyn FnMut()>>,
+}
+
+/// A reCAPTCHA based [`ApplicationVerifier`], required for phone number
+/// authentication.
+///
+/// The widget is cleared when this is dropped.
+pub struct RecaptchaVerifier {
+    verifier: JsRecaptchaVerifier,
+    _callback: Option<Closure<dyn FnMut(String)>>,
+    _expired_callback: Option<Closure<dyn FnMut()>>,
+}
+
+impl RecaptchaVerifier {
+    /// Creates a new verifier rendered into `container`, which is either
+    /// the ID of an HTML element or the element itself.
+    ///
+    /// This uses the argument order of `firebase` 10 and newer, which
+    /// differs from version 9.
+    pub fn new(
+        auth: Auth,
+        container: impl Into<JsValue>,
+        parameters: RecaptchaParameters,
+    ) -> Result<Self, AuthError> {
+        let RecaptchaParameters {
+            size,
+            theme,
+            callback,
+            expired_callback,
+        } = parameters;
+
+        let callback = callback.map(Closure::new);
+        let expired_callback = expired_callback.map(Closure::new);
+
+        let params = js_sys::Object::new();
+
+        js_sys::Reflect::set(&params, &"size".into(), &size.to_string().into()).unwrap();
+        js_sys::Reflect::set(&params, &"theme".into(), &theme.to_string().into()).unwrap();
+
+        if let Some(callback) = &callback {
+            js_sys::Reflect::set(&params, &"callback".into(), callback.as_ref()).unwrap();
+        }
+
+        if let Some(expired_callback) = &expired_callback {
+            js_sys::Reflect::set(
+                &params,
+                &"expired-callback".into(),
+                expired_callback.as_ref(),
+            )
+            .unwrap();
+        }
+
+        let verifier = JsRecaptchaVerifier::new(auth, container.into(), &params)
+            .map_err(|err| err.unchecked_into::<FirebaseError>())?;
+
+        Ok(Self {
+            verifier,
+            _callback: callback,
+            _expired_callback: expired_callback,
+        })
+    }
+
+    /// Renders the widget, returning the reCAPTCHA widget ID.
+    pub async fn render(&self) -> Result<f64, AuthError> {
+        self.verifier
+            .render()
+            .await
+            .map(|id| id.as_f64().expect("widget ID to be a number"))
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+
+    /// Waits for the user to solve the challenge, returning the reCAPTCHA
+    /// response token.
+    pub async fn verify(&self) -> Result<String, AuthError> {
+        self.verifier
+            .verify()
+            .await
+            .map(|token| token.as_string().expect("token to be a string"))
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+
+    /// Clears the widget from the page. This is equivalent to dropping the
+    /// verifier.
+    pub fn clear(self) {}
+}
+
+impl Deref for RecaptchaVerifier {
+    type Target = ApplicationVerifier;
+
+    fn deref(&self) -> &Self::Target {
+        self.verifier.as_ref()
+    }
+}
+
+impl Drop for RecaptchaVerifier {
+    fn drop(&mut self) {
+        // Clearing an already destroyed verifier throws, which can safely
+        // be ignored
+        let _ = self.verifier.clear();
+    }
+}
+
+impl ConfirmationResult {
+    /// Finishes signing in with the verification code sent to the user's
+    /// phone.
+    pub async fn confirm(&self, verification_code: &str) -> Result<UserCredential, AuthError> {
+        self.confirm_js(verification_code)
+            .await
+            .map(|cred| cred.unchecked_into::<UserCredential>())
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+}
+
+impl PhoneAuthProvider {
+    /// Sends a verification code to `phone_number`, returning the
+    /// verification ID to be passed to [`PhoneAuthProvider::credential`].
+    pub async fn verify_phone_number(
+        &self,
+        phone_number: &str,
+        verifier: &ApplicationVerifier,
+    ) -> Result<String, AuthError> {
+        self.verify_phone_number_js(&phone_number.into(), verifier)
+            .await
+            .map(|id| id.as_string().expect("verification ID to be a string"))
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+
+    /// Sends a verification code to `phone_number` in order to enroll it as
+    /// a second factor, using a session obtained from
+    /// [`MultiFactorUser::get_session`].
+    pub async fn verify_phone_number_for_enrollment(
+        &self,
+        phone_number: &str,
+        session: &MultiFactorSession,
+        verifier: &ApplicationVerifier,
+    ) -> Result<String, AuthError> {
+        let phone_info = js_sys::Object::new();
+
+        js_sys::Reflect::set(&phone_info, &"phoneNumber".into(), &phone_number.into()).unwrap();
+        js_sys::Reflect::set(&phone_info, &"session".into(), session).unwrap();
+
+        self.verify_phone_number_js(&phone_info, verifier)
+            .await
+            .map(|id| id.as_string().expect("verification ID to be a string"))
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+
+    /// Sends a verification code to the phone number of an enrolled second
+    /// factor, in order to resolve a sign-in with a [`MultiFactorResolver`].
+    pub async fn verify_phone_number_for_sign_in(
+        &self,
+        hint: &MultiFactorInfo,
+        session: &MultiFactorSession,
+        verifier: &ApplicationVerifier,
+    ) -> Result<String, AuthError> {
+        let phone_info = js_sys::Object::new();
+
+        js_sys::Reflect::set(&phone_info, &"multiFactorHint".into(), hint).unwrap();
+        js_sys::Reflect::set(&phone_info, &"session".into(), session).unwrap();
+
+        self.verify_phone_number_js(&phone_info, verifier)
+            .await
+            .map(|id| id.as_string().expect("verification ID to be a string"))
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+}
+
+#[wasm_bindgen(module = "firebase/auth")]
+extern "C" {
+    #[derive(Clone, Debug)]
+    pub type ApplicationVerifier;
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(js_name = RecaptchaVerifier, extends = ApplicationVerifier)]
+    type JsRecaptchaVerifier;
+    #[derive(Clone, Debug)]
+    pub type ConfirmationResult;
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(extends = AuthProvider)]
+    pub type PhoneAuthProvider;
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(extends = AuthCredential)]
+    pub type PhoneAuthCredential;
+
+    // =========================================================================
+    //                            ApplicationVerifier
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter, js_name = type)]
+    pub fn type_(this: &ApplicationVerifier) -> String;
+
+    // =========================================================================
+    //                            RecaptchaVerifier
+    // =========================================================================
+
+    #[wasm_bindgen(constructor, catch, js_class = RecaptchaVerifier)]
+    fn new(
+        auth: Auth,
+        container: JsValue,
+        parameters: &js_sys::Object,
+    ) -> Result<JsRecaptchaVerifier, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_class = RecaptchaVerifier)]
+    async fn render(this: &JsRecaptchaVerifier) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_class = RecaptchaVerifier)]
+    async fn verify(this: &JsRecaptchaVerifier) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_class = RecaptchaVerifier)]
+    fn clear(this: &JsRecaptchaVerifier) -> Result<(), JsValue>;
+
+    // =========================================================================
+    //                            ConfirmationResult
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter, js_name = verificationId)]
+    pub fn verification_id(this: &ConfirmationResult) -> String;
+
+    #[wasm_bindgen(method, js_name = confirm, catch)]
+    async fn confirm_js(
+        this: &ConfirmationResult,
+        verification_code: &str,
+    ) -> Result<JsValue, JsValue>;
+
+    // =========================================================================
+    //                            PhoneAuthProvider
+    // =========================================================================
+
+    #[wasm_bindgen(constructor)]
+    pub fn new(auth: Auth) -> PhoneAuthProvider;
+
+    #[wasm_bindgen(method, js_name = verifyPhoneNumber, catch)]
+    async fn verify_phone_number_js(
+        this: &PhoneAuthProvider,
+        phone_info: &JsValue,
+        verifier: &ApplicationVerifier,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(static_method_of = PhoneAuthProvider, js_name = credential)]
+    pub fn credential(verification_id: &str, verification_code: &str) -> PhoneAuthCredential;
+
+    #[wasm_bindgen(static_method_of = PhoneAuthProvider, js_name = credentialFromResult)]
+    pub fn credential_from_result(user_credential: &UserCredential) -> Option<PhoneAuthCredential>;
+
+    #[wasm_bindgen(static_method_of = PhoneAuthProvider, js_name = credentialFromError)]
+    pub fn credential_from_error(error: &FirebaseError) -> Option<PhoneAuthCredential>;
+
+    // =========================================================================
+    //                            PhoneAuthCredential
+    // =========================================================================
+
+    #[wasm_bindgen(static_method_of = PhoneAuthCredential, js_name = fromJSON)]
+    pub fn from_json(json: &JsValue) -> Option<PhoneAuthCredential>;
+}
@@ -0,0 +1,335 @@
+use super::{Auth, AuthError, AuthErrorKind, PhoneAuthCredential, SignInProvider, UserCredential};
+use crate::FirebaseError;
+use wasm_bindgen::{prelude::*, JsCast};
+
+/// Returns the methods which can be used to sign in with `email`.
+///
+/// If email enumeration protection is enabled for the project, this always
+/// returns an empty list.
+pub async fn fetch_sign_in_methods_for_email(
+    auth: Auth,
+    email: &str,
+) -> Result<Vec<SignInProvider>, AuthError> {
+    fetch_sign_in_methods_for_email_js(auth, email)
+        .await
+        .map(|methods| {
+            methods
+                .unchecked_into::<js_sys::Array>()
+                .iter()
+                .map(|method| {
+                    method
+                        .as_string()
+                        .expect("sign-in method to be a string")
+                        .parse()
+                        .unwrap()
+                })
+                .collect()
+        })
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Information needed to link an OAuth credential to an existing account,
+/// after sign-in failed with
+/// [`AuthErrorKind::AccountExistsWithDifferentCredential`].
+#[derive(Clone, Debug)]
+pub struct AccountLinkingInfo {
+    /// The email of the existing account.
+    pub email: Option<String>,
+    /// The credential which failed to sign in, to be linked with
+    /// [`link_with_credential`](super::link_with_credential) once the user
+    /// signs into the existing account.
+    pub pending_credential: Option<OAuthCredential>,
+    /// The methods which can be used to sign into the existing account.
+    pub sign_in_methods: Vec<SignInProvider>,
+}
+
+impl AccountLinkingInfo {
+    /// The method the user should be asked to sign in with, if known.
+    pub fn suggested_method(&self) -> Option<&SignInProvider> {
+        self.sign_in_methods.first()
+    }
+}
+
+/// Returns the information needed to resolve an
+/// [`AuthErrorKind::AccountExistsWithDifferentCredential`] error by
+/// linking accounts, or `None` if `error` is of a different kind.
+pub async fn account_linking_info(
+    auth: Auth,
+    error: &AuthError,
+) -> Result<Option<AccountLinkingInfo>, AuthError> {
+    if !matches!(
+        error.kind,
+        AuthErrorKind::AccountExistsWithDifferentCredential
+    ) {
+        return Ok(None);
+    }
+
+    let email = error.email();
+
+    let sign_in_methods = match &email {
+        Some(email) => fetch_sign_in_methods_for_email(auth, email).await?,
+        None => vec![],
+    };
+
+    Ok(Some(AccountLinkingInfo {
+        email,
+        pending_credential: OAuthProvider::credential_from_error(&error.source),
+        sign_in_methods,
+    }))
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, TypedBuilder, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(strip_option, into)))]
+pub struct OAuthCredentialOptions {
+    pub id_token: Option<String>,
+    pub access_token: Option<String>,
+    /// The unhashed nonce used to obtain `id_token`, required for OIDC
+    /// providers which use nonces.
+    pub raw_nonce: Option<String>,
+}
+
+impl OAuthProvider {
+    /// Creates a provider for an OpenID Connect provider configured in
+    /// Identity Platform. `provider_id` must start with `oidc.`.
+    pub fn oidc(provider_id: &str) -> Self {
+        Self::new(provider_id)
+    }
+
+    /// Creates a credential from tokens obtained directly from the
+    /// provider, such as for an OIDC provider.
+    pub fn credential(&self, options: OAuthCredentialOptions) -> OAuthCredential {
+        let options = serde_wasm_bindgen::to_value(&options).unwrap();
+
+        self.credential_js(options)
+    }
+}
+
+impl AuthCredential {
+    /// Serializes the credential into a JSON string, which can be turned
+    /// back into a credential with [`AuthCredential::from_json`].
+    pub fn to_json_string(&self) -> String {
+        js_sys::JSON::stringify(&self.to_json())
+            .expect("credential to be serializable")
+            .into()
+    }
+
+    /// Recreates a credential from the JSON string produced by
+    /// [`AuthCredential::to_json_string`], returning `None` if it is
+    /// invalid.
+    pub fn from_json(json: &str) -> Option<AuthCredential> {
+        let obj = js_sys::JSON::parse(json).ok()?;
+
+        let provider_id = js_sys::Reflect::get(&obj, &"providerId".into())
+            .ok()?
+            .as_string()?;
+
+        match provider_id.as_str() {
+            "password" => EmailAuthCredential::from_json(&obj).map(JsCast::unchecked_into),
+            "phone" => PhoneAuthCredential::from_json(&obj).map(JsCast::unchecked_into),
+            id if id.starts_with("saml.") => {
+                SAMLAuthProvider::credential_from_json(&obj).map(JsCast::unchecked_into)
+            }
+            _ => OAuthCredential::from_json(&obj).map(JsCast::unchecked_into),
+        }
+    }
+}
+
+impl BaseOAuthProvider {
+    /// Replaces the custom OAuth parameters sent to the provider, such as
+    /// `prompt` or `login_hint`.
+    ///
+    /// Reserved parameters, such as `client_id` or `redirect_uri`, are
+    /// ignored by the SDK.
+    pub fn set_custom_parameters<K, V>(&self, params: impl IntoIterator<Item = (K, V)>) -> &Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let obj = js_sys::Object::new();
+
+        for (k, v) in params {
+            js_sys::Reflect::set(
+                &obj,
+                &JsValue::from_str(k.as_ref()),
+                &JsValue::from_str(v.as_ref()),
+            )
+            .unwrap();
+        }
+
+        self.set_custom_parameters_js(&obj);
+
+        self
+    }
+}
+
+#[wasm_bindgen(module = "firebase/auth")]
+extern "C" {
+    #[derive(Clone, Debug)]
+    pub type AuthProvider;
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(extends = AuthProvider)]
+    pub type BaseOAuthProvider;
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(extends = BaseOAuthProvider, extends = AuthProvider)]
+    pub type OAuthProvider;
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(extends = BaseOAuthProvider, extends = AuthProvider)]
+    pub type GoogleAuthProvider;
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(extends = BaseOAuthProvider, extends = AuthProvider)]
+    pub type GithubAuthProvider;
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(extends = AuthProvider)]
+    pub type SAMLAuthProvider;
+    #[derive(Clone, Debug)]
+    pub type EmailAuthProvider;
+    #[derive(Clone, Debug)]
+    pub type AuthCredential;
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(extends = AuthCredential)]
+    pub type EmailAuthCredential;
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(extends = AuthCredential)]
+    pub type OAuthCredential;
+
+    #[wasm_bindgen(js_name = fetchSignInMethodsForEmail, catch)]
+    async fn fetch_sign_in_methods_for_email_js(
+        auth: Auth,
+        email: &str,
+    ) -> Result<JsValue, JsValue>;
+
+    // =========================================================================
+    //                            AuthProvider
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter, js_name = providerId)]
+    pub fn provider_id(this: &AuthProvider) -> String;
+
+    // =========================================================================
+    //                            BaseOAuthProvider
+    // =========================================================================
+
+    #[wasm_bindgen(method, js_name = addScope)]
+    pub fn add_scope(this: &BaseOAuthProvider, scope: &str) -> AuthProvider;
+
+    #[wasm_bindgen(method, js_name = getScopes)]
+    pub fn get_scopes(this: &BaseOAuthProvider) -> Vec<js_sys::JsString>;
+
+    #[wasm_bindgen(method, js_name = setCustomParameters)]
+    fn set_custom_parameters_js(this: &BaseOAuthProvider, params: &js_sys::Object);
+
+    // =========================================================================
+    //                            OAuthProvider
+    // =========================================================================
+
+    /// Creates a provider for a generic OAuth provider, such as `apple.com`
+    /// or `microsoft.com`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(provider_id: &str) -> OAuthProvider;
+
+    #[wasm_bindgen(method, js_name = credential)]
+    fn credential_js(this: &OAuthProvider, options: JsValue) -> OAuthCredential;
+
+    #[wasm_bindgen(static_method_of = OAuthProvider, js_name = credentialFromResult)]
+    pub fn credential_from_result(user_credential: &UserCredential) -> Option<OAuthCredential>;
+
+    #[wasm_bindgen(static_method_of = OAuthProvider, js_name = credentialFromError)]
+    pub fn credential_from_error(error: &FirebaseError) -> Option<OAuthCredential>;
+
+    // =========================================================================
+    //                            GoogleAuthProvider
+    // =========================================================================
+
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> GoogleAuthProvider;
+
+    #[wasm_bindgen(static_method_of = GoogleAuthProvider, js_name = credential)]
+    pub fn credential(id_token: Option<&str>, access_token: Option<&str>) -> OAuthCredential;
+
+    #[wasm_bindgen(static_method_of = GoogleAuthProvider, js_name = credentialFromResult)]
+    pub fn credential_from_result(user_credential: &UserCredential) -> Option<OAuthCredential>;
+
+    #[wasm_bindgen(static_method_of = GoogleAuthProvider, js_name = credentialFromError)]
+    pub fn credential_from_error(error: &FirebaseError) -> Option<OAuthCredential>;
+
+    // =========================================================================
+    //                            GithubAuthProvider
+    // =========================================================================
+
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> GithubAuthProvider;
+
+    #[wasm_bindgen(static_method_of = GithubAuthProvider, js_name = credential)]
+    pub fn credential(access_token: &str) -> OAuthCredential;
+
+    #[wasm_bindgen(static_method_of = GithubAuthProvider, js_name = credentialFromResult)]
+    pub fn credential_from_result(user_credential: &UserCredential) -> Option<OAuthCredential>;
+
+    #[wasm_bindgen(static_method_of = GithubAuthProvider, js_name = credentialFromError)]
+    pub fn credential_from_error(error: &FirebaseError) -> Option<OAuthCredential>;
+
+    // =========================================================================
+    //                            SAMLAuthProvider
+    // =========================================================================
+
+    /// Creates a provider for a SAML provider configured in Identity
+    /// Platform. `provider_id` must start with `saml.`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(provider_id: &str) -> SAMLAuthProvider;
+
+    #[wasm_bindgen(static_method_of = SAMLAuthProvider, js_name = credentialFromResult)]
+    pub fn credential_from_result(user_credential: &UserCredential) -> Option<AuthCredential>;
+
+    #[wasm_bindgen(static_method_of = SAMLAuthProvider, js_name = credentialFromError)]
+    pub fn credential_from_error(error: &FirebaseError) -> Option<AuthCredential>;
+
+    #[wasm_bindgen(static_method_of = SAMLAuthProvider, js_name = credentialFromJSON)]
+    pub fn credential_from_json(json: &JsValue) -> Option<AuthCredential>;
+
+    // =========================================================================
+    //                            EmailAuthProvider
+    // =========================================================================
+
+    #[wasm_bindgen(static_method_of = EmailAuthProvider, js_name = credential)]
+    pub fn credential(email: &str, password: &str) -> EmailAuthCredential;
+
+    #[wasm_bindgen(static_method_of = EmailAuthProvider, js_name = credentialWithLink)]
+    pub fn credential_with_link(email: &str, email_link: &str) -> EmailAuthCredential;
+
+    #[wasm_bindgen(static_method_of = EmailAuthCredential, js_name = fromJSON)]
+    pub fn from_json(json: &JsValue) -> Option<EmailAuthCredential>;
+
+    // =========================================================================
+    //                            AuthCredential
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter, js_name = providerId)]
+    pub fn provider_id(this: &AuthCredential) -> String;
+
+    #[wasm_bindgen(method, getter, js_name = signInMethod)]
+    pub fn sign_in_method(this: &AuthCredential) -> String;
+
+    /// Returns the credential as a JSON object. Use
+    /// [`AuthCredential::to_json_string`] to get a string which can be
+    /// passed to [`AuthCredential::from_json`].
+    #[wasm_bindgen(method, js_name = toJSON)]
+    pub fn to_json(this: &AuthCredential) -> js_sys::Object;
+
+    // =========================================================================
+    //                            OAuthCredential
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter, js_name = accessToken)]
+    pub fn access_token(this: &OAuthCredential) -> Option<String>;
+
+    #[wasm_bindgen(method, getter, js_name = idToken)]
+    pub fn id_token(this: &OAuthCredential) -> Option<String>;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn secret(this: &OAuthCredential) -> Option<String>;
+
+    #[wasm_bindgen(static_method_of = OAuthCredential, js_name = fromJSON)]
+    pub fn from_json(json: &JsValue) -> Option<OAuthCredential>;
+}
@@ -0,0 +1,135 @@
+use super::{AuthError, Claims, User, UserInfo, UserMetadata};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+
+/// A serializable copy of a [`User`], such as for rendering pages on the
+/// server and hydrating them in the browser, or for caching the user
+/// locally.
+///
+/// Unlike [`User`], this type does not call into JS, so it can be used on
+/// non-wasm targets.
+///
+/// `C` is the type of the app's custom claims, see [`Claims`]. Claims are
+/// only included when created with [`UserSnapshot::with_claims`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSnapshot<C = ()> {
+    pub uid: String,
+    pub provider_id: String,
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+    pub phone_number: Option<String>,
+    #[serde(rename = "photoURL")]
+    pub photo_url: Option<String>,
+    pub email_verified: bool,
+    pub is_anonymous: bool,
+    pub tenant_id: Option<String>,
+    pub metadata: UserMetadataSnapshot,
+    pub provider_data: Vec<UserInfoSnapshot>,
+    pub claims: Option<Claims<C>>,
+}
+
+/// A serializable copy of a [`UserInfo`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserInfoSnapshot {
+    pub uid: String,
+    pub provider_id: String,
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+    pub phone_number: Option<String>,
+    #[serde(rename = "photoURL")]
+    pub photo_url: Option<String>,
+}
+
+/// A serializable copy of a [`UserMetadata`].
+///
+/// Times are UTC date strings, as returned by the SDK.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserMetadataSnapshot {
+    pub creation_time: Option<String>,
+    pub last_sign_in_time: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UserSnapshotError {
+    #[error("auth error: {0}")]
+    Auth(
+        #[from]
+        #[source]
+        AuthError,
+    ),
+    /// The ID token's claims do not match the app's custom claims type.
+    #[error("failed to deserialize claims: {0}")]
+    Deserialize(
+        #[from]
+        #[source]
+        serde_wasm_bindgen::Error,
+    ),
+}
+
+impl<C> UserSnapshot<C>
+where
+    C: for<'de> Deserialize<'de>,
+{
+    /// Creates a snapshot of `user`, including the claims of their current
+    /// ID token.
+    pub async fn with_claims(user: &User) -> Result<Self, UserSnapshotError> {
+        let claims = user.get_id_token_result(false).await?.typed_claims()?;
+
+        Ok(Self {
+            claims: Some(claims),
+            ..user.into()
+        })
+    }
+}
+
+impl<C> From<&User> for UserSnapshot<C> {
+    fn from(user: &User) -> Self {
+        let info = UserInfoSnapshot::from(user.unchecked_ref::<UserInfo>());
+
+        Self {
+            uid: info.uid,
+            provider_id: info.provider_id,
+            display_name: info.display_name,
+            email: info.email,
+            phone_number: info.phone_number,
+            photo_url: info.photo_url,
+            email_verified: user.email_verified(),
+            is_anonymous: user.is_anonymous(),
+            tenant_id: user.tenant_id(),
+            metadata: (&user.metadata()).into(),
+            provider_data: user.provider_data().iter().map(Into::into).collect(),
+            claims: None,
+        }
+    }
+}
+
+impl<C> From<User> for UserSnapshot<C> {
+    fn from(user: User) -> Self {
+        (&user).into()
+    }
+}
+
+impl From<&UserInfo> for UserInfoSnapshot {
+    fn from(info: &UserInfo) -> Self {
+        Self {
+            uid: info.uid(),
+            provider_id: info.provider_id(),
+            display_name: info.display_name(),
+            email: info.email(),
+            phone_number: info.phone_number(),
+            photo_url: info.photo_url(),
+        }
+    }
+}
+
+impl From<&UserMetadata> for UserMetadataSnapshot {
+    fn from(metadata: &UserMetadata) -> Self {
+        Self {
+            creation_time: metadata.creation_time(),
+            last_sign_in_time: metadata.last_sign_in_time(),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use super::{Auth, AuthError};
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+/// The default amount of time before a token expires at which
+/// [`IdTokenProvider`] refreshes it.
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Clone, Debug)]
+struct CachedToken {
+    uid: String,
+    token: String,
+    /// Expiration time, in milliseconds since the Unix epoch.
+    expires_at: f64,
+}
+
+/// Provides the current user's ID token, such as for use as a bearer token
+/// for a custom backend.
+///
+/// The token is cached, and is refreshed once it is within the refresh
+/// margin of its expiration time, or when a different user signs in.
+/// Cloning the provider shares the cache.
+#[derive(Clone, Debug)]
+pub struct IdTokenProvider {
+    auth: Auth,
+    refresh_margin: Duration,
+    cached: Rc<RefCell<Option<CachedToken>>>,
+}
+
+impl IdTokenProvider {
+    pub fn new(auth: Auth) -> Self {
+        Self::with_refresh_margin(auth, DEFAULT_REFRESH_MARGIN)
+    }
+
+    pub fn with_refresh_margin(auth: Auth, refresh_margin: Duration) -> Self {
+        Self {
+            auth,
+            refresh_margin,
+            cached: Rc::default(),
+        }
+    }
+
+    /// Returns the ID token of the currently signed in user, or `None` if
+    /// no user is signed in.
+    pub async fn token(&self) -> Result<Option<String>, AuthError> {
+        let Some(user) = self.auth.current_user() else {
+            *self.cached.borrow_mut() = None;
+
+            return Ok(None);
+        };
+
+        let uid = user.uid();
+        let refresh_at = js_sys::Date::now() + self.refresh_margin.as_millis() as f64;
+
+        if let Some(cached) = self.cached.borrow().as_ref() {
+            if cached.uid == uid && cached.expires_at > refresh_at {
+                return Ok(Some(cached.token.clone()));
+            }
+        }
+
+        let mut result = user.get_id_token_result(false).await?;
+
+        if js_sys::Date::parse(&result.expiration_time()) <= refresh_at {
+            result = user.get_id_token_result(true).await?;
+        }
+
+        let token = result.token();
+
+        *self.cached.borrow_mut() = Some(CachedToken {
+            uid,
+            token: token.clone(),
+            expires_at: js_sys::Date::parse(&result.expiration_time()),
+        });
+
+        Ok(Some(token))
+    }
+
+    /// Clears the cached token, so that the next call to
+    /// [`IdTokenProvider::token`] fetches it from the SDK.
+    pub fn invalidate(&self) {
+        *self.cached.borrow_mut() = None;
+    }
+}
@@ -1,8 +1,25 @@
 use super::AuthError;
 use crate::FirebaseError;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr, PickFirst};
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
 use wasm_bindgen::{prelude::*, JsCast};
 
+/// Parses a UTC date string, as returned by the SDK, into a [`SystemTime`],
+/// returning `None` if it is invalid.
+fn parse_utc_time(time: &str) -> Option<SystemTime> {
+    let millis = js_sys::Date::parse(time);
+
+    if millis.is_nan() {
+        return None;
+    }
+
+    Some(SystemTime::UNIX_EPOCH + Duration::from_millis(millis as u64))
+}
+
 impl ParsedToken {
     pub fn custom_claims<T>(&self) -> Result<T, serde_wasm_bindgen::Error>
     where
@@ -12,6 +29,133 @@ impl ParsedToken {
     }
 }
 
+impl IdTokenResult {
+    /// The time the user authenticated, i.e., signed in, as opposed to when
+    /// the token was refreshed.
+    pub fn authenticated_at(&self) -> Option<SystemTime> {
+        parse_utc_time(&self.auth_time())
+    }
+
+    /// The token's expiration time, or `None` if the SDK returned a time
+    /// which cannot be parsed.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        parse_utc_time(&self.expiration_time())
+    }
+
+    pub fn issued_at(&self) -> Option<SystemTime> {
+        parse_utc_time(&self.issued_at_time())
+    }
+
+    /// Whether the token has expired. A token whose expiration time cannot
+    /// be parsed is considered expired.
+    pub fn is_expired(&self) -> bool {
+        // `SystemTime::now()` is unsupported on `wasm32-unknown-unknown`
+        let expires_at = js_sys::Date::parse(&self.expiration_time());
+
+        expires_at.is_nan() || expires_at <= js_sys::Date::now()
+    }
+
+    pub fn sign_in_provider_kind(&self) -> Option<SignInProvider> {
+        self.sign_in_provider()
+            .map(|provider| provider.parse().unwrap())
+    }
+
+    /// Deserializes the token's claims, separating the standard Firebase
+    /// claims from the app's custom claims, `C`.
+    pub fn typed_claims<C>(&self) -> Result<Claims<C>, serde_wasm_bindgen::Error>
+    where
+        C: for<'de> Deserialize<'de>,
+    {
+        serde_wasm_bindgen::from_value(self.claims().unchecked_into())
+    }
+}
+
+/// The provider used to sign in, as reported by an [`IdTokenResult`], or a
+/// method which can be used to sign in, as returned by
+/// [`fetch_sign_in_methods_for_email`](super::fetch_sign_in_methods_for_email).
+#[derive(Clone, Debug, PartialEq, Eq, strum::EnumString, strum::Display)]
+#[non_exhaustive]
+pub enum SignInProvider {
+    #[strum(serialize = "anonymous")]
+    Anonymous,
+    #[strum(serialize = "password")]
+    Password,
+    #[strum(serialize = "emailLink")]
+    EmailLink,
+    #[strum(serialize = "phone")]
+    Phone,
+    #[strum(serialize = "custom")]
+    Custom,
+    #[strum(serialize = "google.com")]
+    Google,
+    #[strum(serialize = "github.com")]
+    Github,
+    #[strum(serialize = "microsoft.com")]
+    Microsoft,
+    #[strum(serialize = "apple.com")]
+    Apple,
+    #[strum(serialize = "facebook.com")]
+    Facebook,
+    #[strum(serialize = "twitter.com")]
+    Twitter,
+    #[strum(serialize = "yahoo.com")]
+    Yahoo,
+    #[strum(default)]
+    Other(String),
+}
+
+/// The claims of an ID token, as returned by [`IdTokenResult::typed_claims`].
+///
+/// Any claims which are not standard claims are deserialized into
+/// `custom`.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Claims<C> {
+    /// Expiration time, in seconds since the Unix epoch.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub exp: u64,
+    /// Issued-at time, in seconds since the Unix epoch.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub iat: u64,
+    /// Authentication time, in seconds since the Unix epoch.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub auth_time: u64,
+    pub sub: String,
+    pub iss: String,
+    pub aud: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub email_verified: Option<bool>,
+    #[serde(default)]
+    pub phone_number: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub picture: Option<String>,
+    pub firebase: FirebaseClaims,
+    #[serde(flatten)]
+    pub custom: C,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FirebaseClaims {
+    /// Maps each linked provider ID to the user's identifiers for it.
+    #[serde(default)]
+    pub identities: HashMap<String, Vec<String>>,
+    pub sign_in_provider: String,
+    #[serde(default)]
+    pub sign_in_second_factor: Option<String>,
+    #[serde(default)]
+    pub tenant: Option<String>,
+}
+
+impl FirebaseClaims {
+    pub fn sign_in_provider_kind(&self) -> SignInProvider {
+        self.sign_in_provider.parse().unwrap()
+    }
+}
+
 #[wasm_bindgen_struct]
 #[opts(module = "firebase/auth", getter)]
 #[derive(Clone, Debug)]
@@ -26,11 +170,11 @@ pub struct UserInfo {
 }
 
 #[wasm_bindgen_struct]
-#[opts(module = "firestore/auth", getter)]
+#[opts(module = "firebase/auth", getter)]
 #[derive(Clone, Debug)]
 pub struct UserMetadata {
-    pub creation_time: String,
-    pub last_sign_in_time: String,
+    pub creation_time: Option<String>,
+    pub last_sign_in_time: Option<String>,
 }
 
 #[wasm_bindgen_struct]
@@ -42,7 +186,7 @@ pub struct IdTokenResult {
     pub issued_at_time: String,
     pub sign_in_provider: Option<String>,
     pub sign_in_second_factor: Option<String>,
-    pub token: Option<String>,
+    pub token: String,
     pub claims: ParsedToken,
 }
 
@@ -51,9 +195,9 @@ pub struct IdTokenResult {
 #[derive(Clone, Debug)]
 pub struct Firebase {
     #[opts(js_name = "sign_in_provider")]
-    pub sign_in_provider: Option<Firebase>,
+    pub sign_in_provider: Option<String>,
     #[opts(js_name = "sign_in_second_factor")]
-    pub sign_in_second_factor: Option<Firebase>,
+    pub sign_in_second_factor: Option<String>,
     pub identities: Option<js_sys::Object>,
 }
 
@@ -74,12 +218,15 @@ pub struct ParsedToken {
 #[derive(Clone, Debug)]
 #[wasm_bindgen(typescript_type = r#"import("firebase/auth").User"#)]
 pub struct User {
+    pub uid: String,
+    pub provider_id: String,
+    pub phone_number: Option<String>,
     pub email_verified: bool,
     pub is_anonymous: bool,
     pub metadata: UserMetadata,
     pub provider_data: Vec<UserInfo>,
     pub refresh_token: String,
-    pub tenant_id: String,
+    pub tenant_id: Option<String>,
 }
 
 #[wasm_bindgen_struct]
@@ -91,26 +238,34 @@ impl User {
             .map_err(|err| err.unchecked_into::<FirebaseError>().into())
     }
 
-    async fn get_id_token(
+    /// Returns the user's ID token, refreshing it if it has expired or
+    /// `force_refresh` is `true`.
+    pub async fn get_id_token(
         &self,
         force_refresh: bool,
-    ) -> MapValue<Result<JsValue, JsValue>, Result<String, JsValue>> {
+    ) -> MapValue<Result<JsValue, JsValue>, Result<String, AuthError>> {
         self.get_id_token_js(force_refresh)
             .await
             .map(JsCast::unchecked_into::<js_sys::JsString>)
-            .map(|token| String::try_from(token).expect("token to be a string"))
+            .map(String::from)
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
     }
 
-    async fn get_id_token_result(
+    pub async fn get_id_token_result(
         &self,
         force_refresh: bool,
-    ) -> MapValue<Result<JsValue, JsValue>, Result<IdTokenResult, JsValue>> {
+    ) -> MapValue<Result<JsValue, JsValue>, Result<IdTokenResult, AuthError>> {
         self.get_id_token_result_js(force_refresh)
             .await
             .map(Into::into)
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
     }
 
-    async fn reload(&self) -> Result<(), JsValue>;
+    pub async fn reload(&self) -> MapValue<Result<(), JsValue>, Result<(), AuthError>> {
+        self.reload_js()
+            .await
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
 
     pub fn to_json(&self) -> js_sys::Object;
 }
@@ -3,11 +3,15 @@ mod bindings;
 use crate::FirebaseError;
 use bindings as b;
 pub use bindings::{
-    add_doc, delete_doc, doc, get_firestore, limit, on_snapshot_doc, on_snapshot_query, query,
-    set_doc, update_doc, CollectionReference, DocumentReference, DocumentSnapshot, Firestore,
-    Query, QueryConstraint, QuerySnapshot, SetDocOptions, Timestamp, Transaction,
+    add_doc, delete_all_persistent_cache_indexes, delete_doc,
+    disable_persistent_cache_index_auto_creation, doc, enable_persistent_cache_index_auto_creation,
+    get_firestore, get_persistent_cache_index_manager, on_snapshot_doc, set_doc, update_doc,
+    write_batch, CollectionReference, DocumentReference, DocumentSnapshot, Firestore,
+    PersistentCacheIndexManager, QuerySnapshot, SetDocOptions, Timestamp, Transaction, VectorValue,
+    WriteBatch,
 };
-use futures::Future;
+use futures::{Future, Stream};
+use serde::{Deserialize, Serialize};
 use std::{cell::RefCell, error::Error, fmt, rc::Rc, time::SystemTime};
 use wasm_bindgen::{
     prelude::{Closure, *},
@@ -83,20 +87,173 @@ pub enum FirestoreErrorKind {
     Other(String),
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
     Asc,
     Desc,
 }
 
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Asc => "asc",
+            Self::Desc => "desc",
+        })
+    }
+}
+
+/// A Firestore query, along with a Rust-side description of how it was
+/// constructed.
+///
+/// The description is used for the [`Debug`] and [`Display`](fmt::Display)
+/// implementations, making queries readable in logs.
+#[derive(Clone, derive_more::Deref)]
+pub struct Query {
+    #[deref]
+    inner: b::Query,
+    collection_path: String,
+    constraints: Vec<QueryConstraintKind>,
+}
+
+impl Query {
+    /// The path of the collection this query targets.
+    pub fn collection_path(&self) -> &str {
+        &self.collection_path
+    }
+
+    /// The constraints this query was constructed with, in order.
+    pub fn constraints(&self) -> &[QueryConstraintKind] {
+        &self.constraints
+    }
+}
+
+impl fmt::Debug for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Query")
+            .field("collection_path", &self.collection_path)
+            .field("constraints", &self.constraints)
+            .finish()
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.collection_path)?;
+
+        for constraint in &self.constraints {
+            write!(f, " {constraint}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A query constraint, along with a Rust-side description of it.
+#[derive(Clone, derive_more::Deref)]
+pub struct QueryConstraint {
+    #[deref]
+    inner: b::QueryConstraint,
+    kind: QueryConstraintKind,
+}
+
+impl QueryConstraint {
+    pub fn kind(&self) -> &QueryConstraintKind {
+        &self.kind
+    }
+}
+
+impl fmt::Debug for QueryConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind.fmt(f)
+    }
+}
+
+impl fmt::Display for QueryConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind.fmt(f)
+    }
+}
+
+/// Describes a [`QueryConstraint`]. Values are stored as their JSON
+/// representation, since the underlying JS values cannot be inspected
+/// from Rust.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum QueryConstraintKind {
+    Where {
+        field_path: String,
+        op: QueryConstraintOp,
+        value: String,
+    },
+    OrderBy {
+        field_path: String,
+        direction: Direction,
+    },
+    Limit(i32),
+    LimitToLast(i32),
+    StartAt(Vec<String>),
+    StartAfter(Vec<String>),
+    EndAt(Vec<String>),
+    EndBefore(Vec<String>),
+}
+
+impl fmt::Display for QueryConstraintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Where {
+                field_path,
+                op,
+                value,
+            } => write!(f, "where {field_path} {op} {value}"),
+            Self::OrderBy {
+                field_path,
+                direction,
+            } => write!(f, "order by {field_path} {direction}"),
+            Self::Limit(limit) => write!(f, "limit {limit}"),
+            Self::LimitToLast(limit) => write!(f, "limit to last {limit}"),
+            Self::StartAt(values) => write!(f, "start at [{}]", values.join(", ")),
+            Self::StartAfter(values) => write!(f, "start after [{}]", values.join(", ")),
+            Self::EndAt(values) => write!(f, "end at [{}]", values.join(", ")),
+            Self::EndBefore(values) => write!(f, "end before [{}]", values.join(", ")),
+        }
+    }
+}
+
+/// Renders a JS value for use in a [`QueryConstraintKind`].
+fn describe_value(value: &JsValue) -> String {
+    if let Some(document) = value.dyn_ref::<DocumentSnapshot>() {
+        return format!("<document {}>", document.ref_().path());
+    }
+
+    js_sys::JSON::stringify(value)
+        .ok()
+        .and_then(|json| json.as_string())
+        .unwrap_or_else(|| format!("{value:?}"))
+}
+
+pub fn query(collection: CollectionReference, constraints: Vec<QueryConstraint>) -> Query {
+    let collection_path = collection.path();
+
+    let (inner, constraints): (Vec<_>, Vec<_>) = constraints
+        .into_iter()
+        .map(|constraint| (constraint.inner, constraint.kind))
+        .unzip();
+
+    Query {
+        inner: b::query(collection, inner),
+        collection_path,
+        constraints,
+    }
+}
+
 pub fn order_by(field_path: &str, direction: Direction) -> QueryConstraint {
-    b::order_by(
-        field_path,
-        match direction {
-            Direction::Asc => "asc",
-            Direction::Desc => "desc",
+    QueryConstraint {
+        inner: b::order_by(field_path, &direction.to_string()),
+        kind: QueryConstraintKind::OrderBy {
+            field_path: field_path.to_owned(),
+            direction,
         },
-    )
+    }
 }
 
 pub fn where_<V: Into<JsValue>>(
@@ -106,11 +263,105 @@ pub fn where_<V: Into<JsValue>>(
 ) -> QueryConstraint {
     let value = value.into();
 
-    b::where_(field_path, &op.to_string(), value)
+    QueryConstraint {
+        kind: QueryConstraintKind::Where {
+            field_path: field_path.to_owned(),
+            op,
+            value: describe_value(&value),
+        },
+        inner: b::where_(field_path, &op.to_string(), value),
+    }
+}
+
+pub fn limit(number: i32) -> QueryConstraint {
+    QueryConstraint {
+        inner: b::limit(number),
+        kind: QueryConstraintKind::Limit(number),
+    }
 }
 
+pub fn limit_to_last(number: i32) -> QueryConstraint {
+    QueryConstraint {
+        inner: b::limit_to_last(number),
+        kind: QueryConstraintKind::LimitToLast(number),
+    }
+}
+
+/// Starts the query results at the given field values, relative to the
+/// query's order. A [`DocumentSnapshot`] can also be passed as the only value.
+pub fn start_at(values: Vec<JsValue>) -> QueryConstraint {
+    QueryConstraint {
+        kind: QueryConstraintKind::StartAt(values.iter().map(describe_value).collect()),
+        inner: b::start_at(values),
+    }
+}
+
+pub fn start_after(values: Vec<JsValue>) -> QueryConstraint {
+    QueryConstraint {
+        kind: QueryConstraintKind::StartAfter(values.iter().map(describe_value).collect()),
+        inner: b::start_after(values),
+    }
+}
+
+pub fn end_at(values: Vec<JsValue>) -> QueryConstraint {
+    QueryConstraint {
+        kind: QueryConstraintKind::EndAt(values.iter().map(describe_value).collect()),
+        inner: b::end_at(values),
+    }
+}
+
+pub fn end_before(values: Vec<JsValue>) -> QueryConstraint {
+    QueryConstraint {
+        kind: QueryConstraintKind::EndBefore(values.iter().map(describe_value).collect()),
+        inner: b::end_before(values),
+    }
+}
+
+pub fn on_snapshot_query(
+    query: Query,
+    observer: &Closure<dyn FnMut(QuerySnapshot)>,
+) -> js_sys::Function {
+    b::on_snapshot_query(query.inner, observer)
+}
+
+/// Creates a [`VectorValue`] which can be written to a document.
+///
+/// The web SDK cannot perform vector searches, which are only available
+/// through the server SDKs.
+pub fn vector(values: &[f64]) -> VectorValue {
+    let values = values
+        .iter()
+        .copied()
+        .map(JsValue::from)
+        .collect::<js_sys::Array>();
+
+    b::vector(values)
+}
+
+impl VectorValue {
+    pub fn to_vec(&self) -> Vec<f64> {
+        self.to_array()
+            .iter()
+            .map(|v| v.as_f64().expect("vector values to be numbers"))
+            .collect()
+    }
+}
+
+impl From<Vec<f64>> for VectorValue {
+    fn from(values: Vec<f64>) -> Self {
+        vector(&values)
+    }
+}
+
+impl From<&[f64]> for VectorValue {
+    fn from(values: &[f64]) -> Self {
+        vector(values)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum QueryConstraintOp {
-    /// `<`o
+    /// `<`
     LessThan,
     /// `<=`
     LessThanEq,
@@ -151,6 +402,102 @@ impl fmt::Display for QueryConstraintOp {
     }
 }
 
+/// Controls how fields set to `serverTimestamp()` that have not yet been
+/// resolved by the backend are returned when reading a [`DocumentSnapshot`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SnapshotOptions {
+    /// Pending server timestamps are returned as `null`.
+    #[default]
+    None,
+    /// Pending server timestamps are returned as an estimate based on the
+    /// local clock.
+    Estimate,
+    /// Pending server timestamps are returned as their previous value, or
+    /// `null` if there was none.
+    Previous,
+}
+
+impl SnapshotOptions {
+    fn to_js(self) -> JsValue {
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Options {
+            server_timestamps: SnapshotOptions,
+        }
+
+        serde_wasm_bindgen::to_value(&Options {
+            server_timestamps: self,
+        })
+        .unwrap()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetFieldError {
+    #[error("firestore error: {0}")]
+    Firestore(
+        #[from]
+        #[source]
+        FirestoreError,
+    ),
+    #[error("failed to deserialize field: {0}")]
+    Deserialize(
+        #[from]
+        #[source]
+        serde_wasm_bindgen::Error,
+    ),
+}
+
+impl DocumentSnapshot {
+    /// Returns the document's fields, resolving pending server timestamps
+    /// according to `options`.
+    pub fn data_with_options(&self, options: SnapshotOptions) -> JsValue {
+        self.data_with_options_js(options.to_js())
+    }
+
+    /// Returns the raw value of the field at `field_path`, or `undefined` if
+    /// it does not exist. Nested fields can be accessed with dot notation,
+    /// i.e., `"address.city"`.
+    pub fn get(&self, field_path: &str) -> Result<JsValue, FirestoreError> {
+        self.get_with_options(field_path, SnapshotOptions::default())
+    }
+
+    pub fn get_with_options(
+        &self,
+        field_path: &str,
+        options: SnapshotOptions,
+    ) -> Result<JsValue, FirestoreError> {
+        self.get_js(field_path, options.to_js()).map_err(Into::into)
+    }
+
+    /// Deserializes only the field at `field_path`, returning `None` if
+    /// the field does not exist.
+    pub fn get_field<T>(&self, field_path: &str) -> Result<Option<T>, GetFieldError>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        self.get_field_with_options(field_path, SnapshotOptions::default())
+    }
+
+    pub fn get_field_with_options<T>(
+        &self,
+        field_path: &str,
+        options: SnapshotOptions,
+    ) -> Result<Option<T>, GetFieldError>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let value = self.get_with_options(field_path, options)?;
+
+        if value.is_undefined() {
+            return Ok(None);
+        }
+
+        Ok(Some(serde_wasm_bindgen::from_value(value)?))
+    }
+}
+
 pub async fn get_doc(doc: DocumentReference) -> Result<DocumentSnapshot, FirestoreError> {
     b::get_doc(doc)
         .await
@@ -158,11 +505,23 @@ pub async fn get_doc(doc: DocumentReference) -> Result<DocumentSnapshot, Firesto
         .map(|snapshot| snapshot.unchecked_into())
 }
 
+#[tracing::instrument(skip_all, fields(query = %query))]
 pub async fn get_docs(query: Query) -> Result<QuerySnapshot, FirestoreError> {
-    b::get_docs(query)
+    let start = js_sys::Date::now();
+
+    let res = b::get_docs(query.inner)
         .await
-        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
-        .map(|snapshot| snapshot.unchecked_into())
+        .map_err(|err| FirestoreError::from(err.unchecked_into::<FirebaseError>()))
+        .map(|snapshot| snapshot.unchecked_into::<QuerySnapshot>());
+
+    let elapsed_ms = js_sys::Date::now() - start;
+
+    match &res {
+        Ok(snapshot) => debug!(elapsed_ms, size = snapshot.size(), "query succeeded"),
+        Err(err) => warn!(elapsed_ms, kind = ?err.kind, "query failed: {err}"),
+    }
+
+    res
 }
 
 pub async fn set_doc_with_options<D: Into<JsValue>>(
@@ -303,6 +662,252 @@ where
         .map_err(|err| TransactionError::User(err))
 }
 
+impl WriteBatch {
+    pub fn set(&self, doc: DocumentReference, data: JsValue) -> Result<Self, FirestoreError> {
+        self.set_js(doc, data).map_err(Into::into)
+    }
+
+    pub fn update(&self, doc: DocumentReference, data: JsValue) -> Result<Self, FirestoreError> {
+        self.update_js(doc, data).map_err(Into::into)
+    }
+
+    pub fn delete(&self, doc: DocumentReference) -> Result<Self, FirestoreError> {
+        self.delete_js(doc).map_err(Into::into)
+    }
+
+    pub async fn commit(&self) -> Result<(), FirestoreError> {
+        self.commit_js()
+            .await
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+}
+
+/// The maximum number of writes Firestore allows in a single [`WriteBatch`].
+const MAX_WRITE_BATCH_SIZE: i32 = 500;
+
+/// A subcollection which should be deleted along with its parent document
+/// by [`delete_recursive`].
+///
+/// The client SDK cannot list the subcollections of a document, so they must
+/// be known ahead of time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Subcollection {
+    pub name: String,
+    /// Subcollections of each document within this subcollection.
+    pub subcollections: Vec<Subcollection>,
+}
+
+impl Subcollection {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            subcollections: vec![],
+        }
+    }
+
+    pub fn with_subcollection(mut self, subcollection: Subcollection) -> Self {
+        self.subcollections.push(subcollection);
+
+        self
+    }
+}
+
+/// Progress reported by [`delete_recursive`] after each commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteProgress {
+    /// The total number of documents deleted so far.
+    pub deleted: usize,
+    /// Whether the root document has been deleted, in which case this is
+    /// the last item of the stream.
+    pub done: bool,
+}
+
+/// A document whose known subcollections must be emptied before it can
+/// itself be deleted.
+struct DeleteFrame {
+    doc: DocumentReference,
+    subcollections: Vec<Subcollection>,
+}
+
+struct DeleteRecursiveState {
+    firestore: Firestore,
+    stack: Vec<DeleteFrame>,
+    deleted: usize,
+}
+
+/// Deletes `doc` along with every document in `known_subcollections`,
+/// recursively.
+///
+/// Documents are deleted depth-first, so a document is only deleted once
+/// all of its known subcollections are empty. Documents without
+/// subcollections are fetched in pages and deleted in chunked write
+/// batches, while the others are deleted one at a time. A
+/// [`DeleteProgress`] is yielded after each commit.
+///
+/// Deleting is driven by polling the returned stream, so dropping it
+/// cancels the operation after the current commit. Since parents outlive
+/// their descendants, a cancelled or failed deletion can be resumed by
+/// calling this again with the same arguments.
+pub fn delete_recursive(
+    doc: DocumentReference,
+    known_subcollections: Vec<Subcollection>,
+) -> impl Stream<Item = Result<DeleteProgress, FirestoreError>> {
+    let state = DeleteRecursiveState {
+        firestore: doc.firestore(),
+        stack: vec![DeleteFrame {
+            doc,
+            subcollections: known_subcollections,
+        }],
+        deleted: 0,
+    };
+
+    futures::stream::try_unfold(state, |mut state| async move {
+        while let Some(frame) = state.stack.last_mut() {
+            let Some(subcollection) = frame.subcollections.last() else {
+                // Every known subcollection is empty, so the document can
+                // safely be deleted
+                let frame = state.stack.pop().unwrap();
+
+                delete_doc(frame.doc)
+                    .await
+                    .map_err(|err| FirestoreError::from(err.unchecked_into::<FirebaseError>()))?;
+
+                state.deleted += 1;
+
+                return Ok(Some((
+                    DeleteProgress {
+                        deleted: state.deleted,
+                        done: state.stack.is_empty(),
+                    },
+                    state,
+                )));
+            };
+
+            let path = format!("{}/{}", frame.doc.path(), subcollection.name);
+            let children = subcollection.subcollections.clone();
+
+            let snapshot = get_docs(query(
+                collection(state.firestore.clone(), &path)?,
+                vec![limit(MAX_WRITE_BATCH_SIZE)],
+            ))
+            .await?;
+
+            if snapshot.empty() {
+                frame.subcollections.pop();
+
+                continue;
+            }
+
+            let docs = snapshot.docs();
+
+            if !children.is_empty() {
+                // Empty the children's subcollections before deleting them
+                state.stack.extend(docs.iter().map(|doc| DeleteFrame {
+                    doc: doc.ref_(),
+                    subcollections: children.clone(),
+                }));
+
+                continue;
+            }
+
+            let batch = write_batch(state.firestore.clone());
+
+            for doc in &docs {
+                batch.delete(doc.ref_())?;
+            }
+
+            batch.commit().await?;
+
+            state.deleted += docs.len();
+
+            return Ok(Some((
+                DeleteProgress {
+                    deleted: state.deleted,
+                    done: false,
+                },
+                state,
+            )));
+        }
+
+        Ok(None)
+    })
+}
+
+/// Client-side indexes used when querying the offline cache.
+///
+/// This uses the same format as the `firestore.indexes.json` file generated
+/// by the Firebase CLI, so such a file can be deserialized directly into this
+/// struct. Field overrides are ignored.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, TypedBuilder, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexConfiguration {
+    #[builder(default)]
+    #[serde(default)]
+    pub indexes: Vec<Index>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, TypedBuilder, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Index {
+    /// The collection ID to which this index applies.
+    #[builder(setter(into))]
+    pub collection_group: String,
+    #[builder(default, setter(strip_option))]
+    pub query_scope: Option<QueryScope>,
+    #[builder(default)]
+    #[serde(default)]
+    pub fields: Vec<IndexField>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, TypedBuilder, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexField {
+    #[builder(setter(into))]
+    pub field_path: String,
+    /// Set for fields used with `array-contains` and `array-contains-any`.
+    #[builder(default, setter(strip_option))]
+    pub array_config: Option<ArrayConfig>,
+    /// Set for fields used with ordering or comparison filters.
+    #[builder(default, setter(strip_option))]
+    pub order: Option<IndexFieldOrder>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum QueryScope {
+    Collection,
+    CollectionGroup,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ArrayConfig {
+    Contains,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IndexFieldOrder {
+    Ascending,
+    Descending,
+}
+
+/// Configures the indexes used by the offline cache, replacing any
+/// previous configuration.
+pub async fn set_index_configuration(
+    firestore: Firestore,
+    configuration: &IndexConfiguration,
+) -> Result<(), FirestoreError> {
+    let configuration = serde_wasm_bindgen::to_value(configuration).unwrap();
+
+    b::set_index_configuration(firestore, configuration)
+        .await
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
 impl From<SystemTime> for Timestamp {
     fn from(time: SystemTime) -> Self {
         Self::from_millis(
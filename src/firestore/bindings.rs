@@ -27,7 +27,13 @@ extern "C" {
     #[derive(Clone, Debug)]
     pub type Transaction;
     #[derive(Clone, Debug)]
+    pub type WriteBatch;
+    #[derive(Clone, Debug)]
     pub type Timestamp;
+    #[derive(Clone, Debug)]
+    pub type VectorValue;
+    #[derive(Clone, Debug)]
+    pub type PersistentCacheIndexManager;
 
     #[wasm_bindgen(js_name = getFirestore)]
     pub fn get_firestore() -> Firestore;
@@ -87,6 +93,24 @@ extern "C" {
     #[wasm_bindgen]
     pub fn limit(number: i32) -> QueryConstraint;
 
+    #[wasm_bindgen(js_name = limitToLast)]
+    pub fn limit_to_last(number: i32) -> QueryConstraint;
+
+    #[wasm_bindgen(js_name = startAt, variadic)]
+    pub fn start_at(values: Vec<JsValue>) -> QueryConstraint;
+
+    #[wasm_bindgen(js_name = startAfter, variadic)]
+    pub fn start_after(values: Vec<JsValue>) -> QueryConstraint;
+
+    #[wasm_bindgen(js_name = endAt, variadic)]
+    pub fn end_at(values: Vec<JsValue>) -> QueryConstraint;
+
+    #[wasm_bindgen(js_name = endBefore, variadic)]
+    pub fn end_before(values: Vec<JsValue>) -> QueryConstraint;
+
+    #[wasm_bindgen(js_name = vector)]
+    pub(crate) fn vector(values: js_sys::Array) -> VectorValue;
+
     #[wasm_bindgen(js_name = deleteDoc, catch)]
     pub async fn delete_doc(doc: DocumentReference) -> Result<(), JsValue>;
 
@@ -96,6 +120,28 @@ extern "C" {
         update_fn: &Closure<dyn FnMut(Transaction) -> js_sys::Promise>,
     ) -> Result<(), JsValue>;
 
+    #[wasm_bindgen(js_name = setIndexConfiguration, catch)]
+    pub async fn set_index_configuration(
+        firestore: Firestore,
+        configuration: JsValue,
+    ) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_name = getPersistentCacheIndexManager)]
+    pub fn get_persistent_cache_index_manager(
+        firestore: Firestore,
+    ) -> Option<PersistentCacheIndexManager>;
+
+    #[wasm_bindgen(js_name = enablePersistentCacheIndexAutoCreation)]
+    pub fn enable_persistent_cache_index_auto_creation(index_manager: &PersistentCacheIndexManager);
+
+    #[wasm_bindgen(js_name = disablePersistentCacheIndexAutoCreation)]
+    pub fn disable_persistent_cache_index_auto_creation(
+        index_manager: &PersistentCacheIndexManager,
+    );
+
+    #[wasm_bindgen(js_name = deleteAllPersistentCacheIndexes)]
+    pub fn delete_all_persistent_cache_indexes(index_manager: &PersistentCacheIndexManager);
+
     // =========================================================================
     //                            QuerySnapshot
     // =========================================================================
@@ -119,14 +165,24 @@ extern "C" {
     #[wasm_bindgen(method)]
     pub fn data(this: &DocumentSnapshot) -> JsValue;
 
+    #[wasm_bindgen(method, js_name = data)]
+    pub(crate) fn data_with_options_js(this: &DocumentSnapshot, options: JsValue) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = get, catch)]
+    pub(crate) fn get_js(
+        this: &DocumentSnapshot,
+        field_path: &str,
+        options: JsValue,
+    ) -> Result<JsValue, FirebaseError>;
+
     #[wasm_bindgen(method, getter)]
     pub fn id(this: &DocumentSnapshot) -> String;
 
-    #[wasm_bindgen(method, js_name = "ref")]
+    #[wasm_bindgen(method, getter, js_name = "ref")]
     pub fn ref_(this: &DocumentSnapshot) -> DocumentReference;
 
     // =========================================================================
-    //                            DocumentSnapshot
+    //                            Transaction
     // =========================================================================
 
     #[wasm_bindgen(method, catch, js_name = get)]
@@ -155,6 +211,36 @@ extern "C" {
         doc: DocumentReference,
     ) -> Result<Transaction, FirebaseError>;
 
+    // =========================================================================
+    //                            WriteBatch
+    // =========================================================================
+
+    #[wasm_bindgen(js_name = writeBatch)]
+    pub fn write_batch(firestore: Firestore) -> WriteBatch;
+
+    #[wasm_bindgen(method, js_name = set, catch)]
+    pub(crate) fn set_js(
+        this: &WriteBatch,
+        doc: DocumentReference,
+        data: JsValue,
+    ) -> Result<WriteBatch, FirebaseError>;
+
+    #[wasm_bindgen(method, js_name = update, catch)]
+    pub(crate) fn update_js(
+        this: &WriteBatch,
+        doc: DocumentReference,
+        data: JsValue,
+    ) -> Result<WriteBatch, FirebaseError>;
+
+    #[wasm_bindgen(method, js_name = delete, catch)]
+    pub(crate) fn delete_js(
+        this: &WriteBatch,
+        doc: DocumentReference,
+    ) -> Result<WriteBatch, FirebaseError>;
+
+    #[wasm_bindgen(method, js_name = commit, catch)]
+    pub(crate) async fn commit_js(this: &WriteBatch) -> Result<(), JsValue>;
+
     // =========================================================================
     //                            Timestamp
     // =========================================================================
@@ -177,6 +263,16 @@ extern "C" {
     #[wasm_bindgen(method, js_name = isEqual)]
     pub fn is_equal(this: &Timestamp, other: &Timestamp) -> bool;
 
+    // =========================================================================
+    //                            VectorValue
+    // =========================================================================
+
+    #[wasm_bindgen(method, js_name = toArray)]
+    pub(crate) fn to_array(this: &VectorValue) -> js_sys::Array;
+
+    #[wasm_bindgen(method, js_name = isEqual)]
+    pub fn is_equal(this: &VectorValue, other: &VectorValue) -> bool;
+
     // =========================================================================
     //                            DocumentReference
     // =========================================================================
@@ -186,6 +282,19 @@ extern "C" {
 
     #[wasm_bindgen(method, getter)]
     pub fn id(this: &DocumentReference) -> String;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn firestore(this: &DocumentReference) -> Firestore;
+
+    // =========================================================================
+    //                            CollectionReference
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter)]
+    pub fn path(this: &CollectionReference) -> String;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn id(this: &CollectionReference) -> String;
 }
 
 impl PartialEq for Timestamp {
@@ -201,3 +310,9 @@ impl PartialEq for DocumentReference {
     }
 }
 impl Eq for DocumentReference {}
+
+impl PartialEq for VectorValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_equal(other)
+    }
+}
@@ -1,4 +1,5 @@
-//! This crate provides bindings to the Firebase v9 JS SDK.
+//! This crate provides bindings to the modular Firebase JS SDK, and requires
+//! `firebase` 10.12 or newer.
 //!
 //! There is much to do, and I am only sharing it this early in
 //! the hopes that someone might help me generate the remaining
@@ -49,3 +50,16 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     pub fn message(this: &FirebaseError) -> String;
 }
+
+#[wasm_bindgen(module = "firebase/app")]
+extern "C" {
+    #[derive(Clone, Debug)]
+    pub type FirebaseApp;
+
+    /// Returns the default app, which must have already been initialized.
+    #[wasm_bindgen(js_name = getApp, catch)]
+    pub fn get_app() -> Result<FirebaseApp, FirebaseError>;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &FirebaseApp) -> String;
+}
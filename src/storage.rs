@@ -1,19 +1,135 @@
 mod bindings;
 
+use crate::FirebaseError;
 pub use bindings::{
-    delete_object, get_download_url, get_storage, ref_, upload_bytes, FullMetadata, Ref,
-    SettableMetadata, Storage, UploadMetadata, UploadMetadataOptions, UploadTask,
-    UploadTaskSnapshot,
+    get_storage, ref_, upload_bytes, FullMetadata, Ref, SettableMetadata, Storage, UploadMetadata,
+    UploadMetadataOptions, UploadTask, UploadTaskSnapshot,
 };
-use futures::Stream;
+use bytes::Bytes;
+use futures::{Stream, TryStreamExt};
+use serde::Serialize;
 use std::{
     cell::RefCell,
+    error::Error,
+    fmt,
     pin::Pin,
     rc::Rc,
     task::{Context, Poll, Waker},
 };
 use wasm_bindgen::{prelude::*, JsCast};
 
+#[derive(Clone, Debug, derive_more::Deref)]
+pub struct StorageError {
+    pub kind: StorageErrorKind,
+    #[deref]
+    pub source: FirebaseError,
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.source.fmt(f)
+    }
+}
+
+impl Error for StorageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl From<FirebaseError> for StorageError {
+    fn from(err: FirebaseError) -> Self {
+        let kind = err.code().parse().unwrap();
+
+        Self { kind, source: err }
+    }
+}
+
+impl StorageError {
+    /// Converts an error which may not have come from the SDK, such as a
+    /// `TypeError` or `DOMException` thrown while reading a download.
+    ///
+    /// Errors without a Firebase error code are wrapped in an error with
+    /// [`StorageErrorKind::Unknown`], keeping their message.
+    fn from_js(err: JsValue) -> Self {
+        let has_code = js_sys::Reflect::get(&err, &"code".into())
+            .map(|code| code.is_string())
+            .unwrap_or_default();
+
+        if has_code {
+            return err.unchecked_into::<FirebaseError>().into();
+        }
+
+        let message = js_sys::Reflect::get(&err, &"message".into())
+            .ok()
+            .and_then(|message| message.as_string())
+            .or_else(|| err.as_string())
+            .unwrap_or_else(|| "unknown error".to_string());
+
+        let wrapped = js_sys::Error::new(&message);
+
+        wrapped.set_name("FirebaseError");
+        js_sys::Reflect::set(&wrapped, &"code".into(), &"storage/unknown".into()).unwrap();
+        js_sys::Reflect::set(&wrapped, &"cause".into(), &err).unwrap();
+
+        wrapped.unchecked_into::<FirebaseError>().into()
+    }
+}
+
+/// The error codes emitted by the Firebase Storage SDK.
+#[derive(Clone, Debug, PartialEq, Eq, strum::EnumString)]
+#[non_exhaustive]
+pub enum StorageErrorKind {
+    #[strum(serialize = "storage/unknown")]
+    Unknown,
+    #[strum(serialize = "storage/object-not-found")]
+    ObjectNotFound,
+    #[strum(serialize = "storage/bucket-not-found")]
+    BucketNotFound,
+    #[strum(serialize = "storage/project-not-found")]
+    ProjectNotFound,
+    #[strum(serialize = "storage/quota-exceeded")]
+    QuotaExceeded,
+    #[strum(serialize = "storage/unauthenticated")]
+    Unauthenticated,
+    #[strum(serialize = "storage/unauthorized")]
+    Unauthorized,
+    #[strum(serialize = "storage/unauthorized-app")]
+    UnauthorizedApp,
+    #[strum(serialize = "storage/retry-limit-exceeded")]
+    RetryLimitExceeded,
+    #[strum(serialize = "storage/invalid-checksum")]
+    InvalidChecksum,
+    #[strum(serialize = "storage/canceled")]
+    Canceled,
+    #[strum(serialize = "storage/invalid-event-name")]
+    InvalidEventName,
+    #[strum(serialize = "storage/invalid-url")]
+    InvalidUrl,
+    #[strum(serialize = "storage/invalid-argument")]
+    InvalidArgument,
+    #[strum(serialize = "storage/no-default-bucket")]
+    NoDefaultBucket,
+    #[strum(serialize = "storage/cannot-slice-blob")]
+    CannotSliceBlob,
+    #[strum(serialize = "storage/server-file-wrong-size")]
+    ServerFileWrongSize,
+    #[strum(serialize = "storage/no-download-url")]
+    NoDownloadUrl,
+    #[strum(serialize = "storage/invalid-root-operation")]
+    InvalidRootOperation,
+    #[strum(serialize = "storage/invalid-format")]
+    InvalidFormat,
+    #[strum(serialize = "storage/internal-error")]
+    InternalError,
+    #[strum(serialize = "storage/unsupported-environment")]
+    UnsupportedEnvironment,
+    #[strum(serialize = "storage/app-deleted")]
+    AppDeleted,
+    #[strum(default)]
+    Other(String),
+}
+
 impl UploadTask {
     pub fn async_iter(&self) -> UploadTaskAsyncIter {
         let waker: Rc<RefCell<Option<Waker>>> = Rc::default();
@@ -87,7 +203,7 @@ impl Drop for UploadTaskAsyncIter {
 }
 
 impl Stream for UploadTaskAsyncIter {
-    type Item = Result<UploadTaskSnapshot, JsValue>;
+    type Item = Result<UploadTaskSnapshot, StorageError>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         // Update waker
@@ -95,7 +211,7 @@ impl Stream for UploadTaskAsyncIter {
 
         if *self.completed.borrow() {
             if let Some(err) = self.err.borrow_mut().take() {
-                Poll::Ready(Some(Err(err)))
+                Poll::Ready(Some(Err(err.unchecked_into::<FirebaseError>().into())))
             } else {
                 Poll::Ready(None)
             }
@@ -107,8 +223,202 @@ impl Stream for UploadTaskAsyncIter {
     }
 }
 
-pub async fn get_metadata(ref_: Ref) -> Result<FullMetadata, JsValue> {
+pub async fn get_download_url(ref_: Ref) -> Result<String, StorageError> {
+    bindings::get_download_url(ref_)
+        .await
+        .map(|url| url.as_string().expect("download URL to be a string"))
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+pub async fn delete_object(ref_: Ref) -> Result<(), StorageError> {
+    bindings::delete_object(ref_)
+        .await
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+pub async fn get_metadata(ref_: Ref) -> Result<FullMetadata, StorageError> {
     bindings::get_metadata(ref_)
         .await
         .map(|m| m.unchecked_into())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Downloads the object at `ref_`.
+///
+/// If `max_download_size_bytes` is set, the download fails if the object is
+/// larger.
+pub async fn get_bytes(
+    ref_: Ref,
+    max_download_size_bytes: Option<u64>,
+) -> Result<Vec<u8>, StorageError> {
+    bindings::get_bytes(ref_, max_download_size_bytes.map(|max| max as f64))
+        .await
+        .map(|buffer| js_sys::Uint8Array::new(&buffer).to_vec())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Downloads the object at `ref_` as a [`Blob`](web_sys::Blob).
+///
+/// If `max_download_size_bytes` is set, the download fails if the object is
+/// larger.
+pub async fn get_blob(
+    ref_: Ref,
+    max_download_size_bytes: Option<u64>,
+) -> Result<web_sys::Blob, StorageError> {
+    bindings::get_blob(ref_, max_download_size_bytes.map(|max| max as f64))
+        .await
+        .map(|blob| blob.unchecked_into())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Downloads the object at `ref_` as a stream of chunks.
+///
+/// The browser SDK cannot stream downloads, so the object is first
+/// downloaded in full with [`get_blob`], then read in chunks.
+///
+/// If `max_download_size_bytes` is set, the download fails if the object is
+/// larger.
+pub fn get_stream(
+    ref_: Ref,
+    max_download_size_bytes: Option<u64>,
+) -> impl Stream<Item = Result<Bytes, StorageError>> {
+    futures::stream::once(get_blob(ref_, max_download_size_bytes))
+        .map_ok(|blob| read_blob(&blob))
+        .try_flatten()
+}
+
+/// Reads `blob` in chunks, as they become available.
+fn read_blob(blob: &web_sys::Blob) -> impl Stream<Item = Result<Bytes, StorageError>> {
+    let reader = blob
+        .stream()
+        .get_reader()
+        .unchecked_into::<web_sys::ReadableStreamDefaultReader>();
+
+    futures::stream::try_unfold(reader, |reader| async move {
+        let chunk = wasm_bindgen_futures::JsFuture::from(reader.read())
+            .await
+            .map_err(StorageError::from_js)?;
+
+        let done = js_sys::Reflect::get(&chunk, &"done".into())
+            .unwrap()
+            .is_truthy();
+
+        if done {
+            return Ok(None);
+        }
+
+        let value = js_sys::Reflect::get(&chunk, &"value".into()).unwrap();
+        let bytes = Bytes::from(js_sys::Uint8Array::new(&value).to_vec());
+
+        Ok(Some((bytes, reader)))
+    })
+}
+
+/// Formats the reference as a `gs://` URL.
+impl fmt::Display for Ref {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_js())
+    }
+}
+
+/// The result of listing the objects under a [`Ref`].
+#[derive(Clone, Debug)]
+pub struct ListResult {
+    /// References to the "folders" directly under the listed reference.
+    pub prefixes: Vec<Ref>,
+    /// References to the objects directly under the listed reference.
+    pub items: Vec<Ref>,
+    /// The token to pass to [`ListOptions::page_token`] to fetch the next
+    /// page, or `None` if this is the last page.
+    pub next_page_token: Option<String>,
+}
+
+impl From<bindings::JsListResult> for ListResult {
+    fn from(result: bindings::JsListResult) -> Self {
+        Self {
+            prefixes: result.prefixes(),
+            items: result.items(),
+            next_page_token: result.next_page_token(),
+        }
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(strip_option, into)))]
+pub struct ListOptions {
+    /// The maximum number of results per page, up to 1000, which is also the
+    /// default.
+    pub max_results: Option<u32>,
+    /// The token returned by a previous [`list`] call, to resume listing
+    /// from.
+    pub page_token: Option<String>,
+}
+
+/// Lists all objects and prefixes under `ref_`.
+///
+/// This fetches every page of results, which may be slow and memory
+/// intensive for large directories. Prefer [`list`] or [`Ref::list_stream`]
+/// for those.
+pub async fn list_all(ref_: Ref) -> Result<ListResult, StorageError> {
+    bindings::list_all(ref_)
+        .await
+        .map(|result| result.unchecked_into::<bindings::JsListResult>().into())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+/// Lists a page of objects and prefixes under `ref_`.
+pub async fn list(ref_: Ref, options: &ListOptions) -> Result<ListResult, StorageError> {
+    let options = serde_wasm_bindgen::to_value(options).unwrap();
+
+    bindings::list(ref_, options)
+        .await
+        .map(|result| result.unchecked_into::<bindings::JsListResult>().into())
+        .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+}
+
+impl Ref {
+    /// Creates a reference from a `gs://` or `https://` URL.
+    pub fn from_url(storage: Storage, url: &str) -> Result<Self, StorageError> {
+        bindings::ref_from_url(storage, url)
+            .map_err(|err| err.unchecked_into::<FirebaseError>().into())
+    }
+
+    /// Returns a reference to `path`, relative to this reference.
+    pub fn child(&self, path: &str) -> Self {
+        bindings::ref_child(self, path)
+    }
+
+    /// Returns a stream of pages of objects and prefixes under this
+    /// reference, following page tokens until all pages have been fetched.
+    pub fn list_stream(
+        &self,
+        max_results: Option<u32>,
+    ) -> impl Stream<Item = Result<ListResult, StorageError>> {
+        let ref_ = self.clone();
+
+        // `None` once the last page has been fetched
+        let page_token = Some(None);
+
+        futures::stream::try_unfold(page_token, move |page_token| {
+            let ref_ = ref_.clone();
+
+            async move {
+                let Some(page_token) = page_token else {
+                    return Ok(None);
+                };
+
+                let options = ListOptions {
+                    max_results,
+                    page_token,
+                };
+
+                let result = list(ref_, &options).await?;
+                let next_page_token = result.next_page_token.clone().map(Some);
+
+                Ok(Some((result, next_page_token)))
+            }
+        })
+    }
 }
@@ -1,6 +1,8 @@
+use super::StorageError;
+use crate::FirebaseError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use wasm_bindgen::prelude::*;
+use wasm_bindgen::{prelude::*, JsCast};
 
 #[derive(Debug, Clone, TypedBuilder, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -40,17 +42,19 @@ pub fn upload_bytes(
     ref_: Ref,
     data: &web_sys::Blob,
     metadata: Option<UploadMetadataOptions>,
-) -> Result<UploadTask, JsValue> {
+) -> Result<UploadTask, StorageError> {
     let serializer = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
 
     let metadata = metadata.serialize(&serializer).unwrap();
 
-    upload_bytes_(ref_, data, metadata)
+    upload_bytes_(ref_, data, metadata).map_err(|err| err.unchecked_into::<FirebaseError>().into())
 }
 
 #[wasm_bindgen(module = "firebase/storage")]
 extern "C" {
+    #[derive(Clone, Debug)]
     pub type Storage;
+    #[derive(Clone, Debug)]
     pub type Ref;
     pub type UploadTask;
     pub type UploadTaskSnapshot;
@@ -60,6 +64,8 @@ extern "C" {
     #[wasm_bindgen(extends = UploadMetadata)]
     pub type FullMetadata;
     pub type TaskState;
+    #[wasm_bindgen(js_name = ListResult)]
+    pub(super) type JsListResult;
 
     #[wasm_bindgen(js_name = getStorage)]
     pub fn get_storage() -> Storage;
@@ -67,6 +73,12 @@ extern "C" {
     #[wasm_bindgen(js_name = ref)]
     pub fn ref_(storage: Storage, path: &str) -> Ref;
 
+    #[wasm_bindgen(js_name = ref, catch)]
+    pub(super) fn ref_from_url(storage: Storage, url: &str) -> Result<Ref, JsValue>;
+
+    #[wasm_bindgen(js_name = ref)]
+    pub(super) fn ref_child(parent: &Ref, path: &str) -> Ref;
+
     #[wasm_bindgen(js_name = uploadBytesResumable, catch)]
     fn upload_bytes_(
         ref_: Ref,
@@ -75,13 +87,61 @@ extern "C" {
     ) -> Result<UploadTask, JsValue>;
 
     #[wasm_bindgen(js_name = getDownloadURL, catch)]
-    pub async fn get_download_url(ref_: Ref) -> Result<JsValue, JsValue>;
+    pub(super) async fn get_download_url(ref_: Ref) -> Result<JsValue, JsValue>;
 
     #[wasm_bindgen(js_name = deleteObject, catch)]
-    pub async fn delete_object(ref_: Ref) -> Result<(), JsValue>;
+    pub(super) async fn delete_object(ref_: Ref) -> Result<(), JsValue>;
 
     #[wasm_bindgen(js_name = getMetadata, catch)]
-    pub async fn get_metadata(ref_: Ref) -> Result<JsValue, JsValue>;
+    pub(super) async fn get_metadata(ref_: Ref) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = listAll, catch)]
+    pub(super) async fn list_all(ref_: Ref) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = list, catch)]
+    pub(super) async fn list(ref_: Ref, options: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = getBytes, catch)]
+    pub(super) async fn get_bytes(
+        ref_: Ref,
+        max_download_size_bytes: Option<f64>,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = getBlob, catch)]
+    pub(super) async fn get_blob(
+        ref_: Ref,
+        max_download_size_bytes: Option<f64>,
+    ) -> Result<JsValue, JsValue>;
+
+    // =========================================================================
+    //                                 Ref
+    // =========================================================================
+
+    /// The name of the bucket containing the object.
+    #[wasm_bindgen(method, getter)]
+    pub fn bucket(this: &Ref) -> String;
+
+    /// The path of the object within its bucket, without a leading slash.
+    #[wasm_bindgen(method, getter, js_name = fullPath)]
+    pub fn full_path(this: &Ref) -> String;
+
+    /// The last component of the object's path.
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &Ref) -> String;
+
+    /// The reference to the parent location, or `None` if this is the root.
+    #[wasm_bindgen(method, getter)]
+    pub fn parent(this: &Ref) -> Option<Ref>;
+
+    /// The reference to the root of the bucket.
+    #[wasm_bindgen(method, getter)]
+    pub fn root(this: &Ref) -> Ref;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn storage(this: &Ref) -> Storage;
+
+    #[wasm_bindgen(method, js_name = toString)]
+    pub(super) fn to_string_js(this: &Ref) -> String;
 
     // =========================================================================
     //                              UploadTask
@@ -192,4 +252,17 @@ extern "C" {
 
     #[wasm_bindgen(method, getter, js_name = ref)]
     pub fn ref_(this: &FullMetadata) -> Option<Ref>;
+
+    // =========================================================================
+    //                              ListResult
+    // =========================================================================
+
+    #[wasm_bindgen(method, getter)]
+    pub(super) fn prefixes(this: &JsListResult) -> Vec<Ref>;
+
+    #[wasm_bindgen(method, getter)]
+    pub(super) fn items(this: &JsListResult) -> Vec<Ref>;
+
+    #[wasm_bindgen(method, getter, js_name = nextPageToken)]
+    pub(super) fn next_page_token(this: &JsListResult) -> Option<String>;
 }
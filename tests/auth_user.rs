@@ -0,0 +1,121 @@
+//! Tests the `auth::user` bindings against the stub `firebase/auth` module in
+//! `tests/mock-firebase`, which `npm install` links in place of the real SDK.
+//!
+//! The stub exports the classes which the bindings import, so a binding with
+//! the wrong module path fails its `instanceof` check.
+
+use firebase_wasm::auth::{get_auth, IdTokenResult, User, UserInfo, UserMetadata};
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "firebase/auth")]
+extern "C" {
+    #[wasm_bindgen(js_name = __mockUser)]
+    fn mock_user(signed_in: bool) -> User;
+
+    #[wasm_bindgen(js_name = __mockIdTokenResult)]
+    fn mock_id_token_result(second_factor: Option<&str>) -> IdTokenResult;
+}
+
+#[wasm_bindgen_test]
+fn bindings_resolve_firebase_auth() {
+    let user = get_auth().current_user().expect("stub to have a current user");
+
+    assert!(user.is_instance_of::<User>());
+    assert!(user.is_instance_of::<UserInfo>());
+    assert!(user.metadata().is_instance_of::<UserMetadata>());
+    assert!(mock_id_token_result(None).is_instance_of::<IdTokenResult>());
+}
+
+#[wasm_bindgen_test]
+fn user_accessors() {
+    let user = mock_user(true);
+
+    assert_eq!(user.uid(), "uid-1");
+    assert_eq!(user.provider_id(), "firebase");
+    assert_eq!(user.phone_number().as_deref(), Some("+15555550100"));
+    assert_eq!(user.tenant_id().as_deref(), Some("tenant-1"));
+}
+
+#[wasm_bindgen_test]
+fn user_nullable_getters() {
+    let user = mock_user(false);
+
+    assert_eq!(user.phone_number(), None);
+    assert_eq!(user.tenant_id(), None);
+}
+
+#[wasm_bindgen_test]
+fn user_metadata_times() {
+    let metadata = mock_user(true).metadata();
+
+    assert_eq!(
+        metadata.creation_time().as_deref(),
+        Some("Mon, 01 Jan 2024 00:00:00 GMT")
+    );
+    assert_eq!(
+        metadata.last_sign_in_time().as_deref(),
+        Some("Tue, 02 Jan 2024 00:00:00 GMT")
+    );
+
+    let metadata = mock_user(false).metadata();
+
+    assert_eq!(metadata.creation_time(), None);
+    assert_eq!(metadata.last_sign_in_time(), None);
+}
+
+#[wasm_bindgen_test]
+fn user_info() {
+    let info = mock_user(true).unchecked_into::<UserInfo>();
+
+    assert_eq!(info.uid(), "uid-1");
+    assert_eq!(info.display_name(), None);
+    assert_eq!(info.email().as_deref(), Some("user@example.com"));
+    assert_eq!(info.photo_url(), None);
+}
+
+#[wasm_bindgen_test]
+fn user_provider_data() {
+    let provider_data = mock_user(true).provider_data();
+
+    assert_eq!(provider_data.len(), 1);
+
+    let info = &provider_data[0];
+
+    assert!(info.is_instance_of::<UserInfo>());
+    assert_eq!(info.uid(), "google-uid-1");
+    assert_eq!(info.provider_id(), "google.com");
+    assert_eq!(info.display_name().as_deref(), Some("Jane Doe"));
+    assert_eq!(info.email().as_deref(), Some("user@example.com"));
+    assert_eq!(info.phone_number(), None);
+    assert_eq!(
+        info.photo_url().as_deref(),
+        Some("https://example.com/photo.png")
+    );
+}
+
+#[wasm_bindgen_test]
+fn id_token_result_token() {
+    let result = mock_id_token_result(None);
+
+    assert_eq!(result.token(), "id-token");
+}
+
+#[wasm_bindgen_test]
+fn firebase_claims() {
+    let firebase = mock_id_token_result(Some("totp"))
+        .claims()
+        .firebase()
+        .expect("claims to contain firebase");
+
+    assert_eq!(firebase.sign_in_provider().as_deref(), Some("password"));
+    assert_eq!(firebase.sign_in_second_factor().as_deref(), Some("totp"));
+    assert!(firebase.identities().is_some());
+
+    let firebase = mock_id_token_result(None)
+        .claims()
+        .firebase()
+        .expect("claims to contain firebase");
+
+    assert_eq!(firebase.sign_in_second_factor(), None);
+}
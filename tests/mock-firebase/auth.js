@@ -0,0 +1,95 @@
+// A stub of `firebase/auth`, which the tests resolve in place of the real
+// SDK. The real SDK does not export the `User`, `UserInfo`, `UserMetadata`
+// and `IdTokenResult` classes, but they are exported here so that the tests
+// can check that the bindings import them from `firebase/auth`.
+
+class UserInfo {
+  constructor(fields) {
+    Object.assign(this, fields);
+  }
+}
+
+class UserMetadata {
+  constructor(fields) {
+    Object.assign(this, fields);
+  }
+}
+
+class User extends UserInfo {}
+
+class IdTokenResult {
+  constructor(fields) {
+    Object.assign(this, fields);
+  }
+}
+
+function mockUser(signedIn) {
+  return new User({
+    uid: "uid-1",
+    providerId: "firebase",
+    displayName: null,
+    email: "user@example.com",
+    phoneNumber: signedIn ? "+15555550100" : null,
+    photoURL: null,
+    emailVerified: true,
+    isAnonymous: false,
+    metadata: new UserMetadata(
+      signedIn
+        ? {
+            creationTime: "Mon, 01 Jan 2024 00:00:00 GMT",
+            lastSignInTime: "Tue, 02 Jan 2024 00:00:00 GMT",
+          }
+        : {}
+    ),
+    providerData: [
+      new UserInfo({
+        uid: "google-uid-1",
+        providerId: "google.com",
+        displayName: "Jane Doe",
+        email: "user@example.com",
+        phoneNumber: null,
+        photoURL: "https://example.com/photo.png",
+      }),
+    ],
+    refreshToken: "refresh-token",
+    tenantId: signedIn ? "tenant-1" : null,
+  });
+}
+
+function mockIdTokenResult(secondFactor) {
+  return new IdTokenResult({
+    authTime: "Mon, 01 Jan 2024 00:00:00 GMT",
+    expirationTime: "Mon, 01 Jan 2024 01:00:00 GMT",
+    issuedAtTime: "Mon, 01 Jan 2024 00:00:00 GMT",
+    signInProvider: "password",
+    signInSecondFactor: secondFactor,
+    token: "id-token",
+    claims: {
+      exp: "1704070800",
+      sub: "uid-1",
+      auth_time: "1704067200",
+      iat: "1704067200",
+      firebase: {
+        sign_in_provider: "password",
+        sign_in_second_factor: secondFactor,
+        identities: { email: ["user@example.com"] },
+      },
+    },
+  });
+}
+
+const auth = { currentUser: mockUser(true) };
+
+function getAuth() {
+  return auth;
+}
+
+module.exports = {
+  User,
+  UserInfo,
+  UserMetadata,
+  IdTokenResult,
+  getAuth,
+  __mockUser: mockUser,
+  __mockIdTokenResult: mockIdTokenResult,
+};